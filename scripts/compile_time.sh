@@ -0,0 +1,74 @@
+#!/bin/sh
+# Measures compile time of the crate for every arity feature, and of a
+# downstream crate calling `join` and `split` for every pair of lengths 0..=13.
+#
+# usage: scripts/compile_time.sh [git revision]
+#
+# Measures the working tree by default, or the given revision, e.g. run it
+# with the revision before a change and with `HEAD` to compare them.
+set -e
+
+CRATE=$(cd "$(dirname "$0")/.." && pwd)
+WORK=$(mktemp -d)
+trap 'rm -rf "$WORK"' EXIT
+
+if [ -n "$1" ]; then
+    SRC="$WORK/src"
+    mkdir -p "$SRC"
+    git -C "$CRATE" archive "$1" | tar -x -C "$SRC"
+    echo "revision $(git -C "$CRATE" rev-parse --short "$1")"
+else
+    SRC="$CRATE"
+    echo "working tree"
+fi
+
+elapsed() {
+    awk "BEGIN { printf \"%.2fs\", $2 - $1 }"
+}
+
+for features in "" arity-16 arity-24 arity-32; do
+    if [ -n "$features" ] && ! grep -q "^$features = " "$SRC/Cargo.toml"; then
+        continue
+    fi
+    start=$(date +%s.%N)
+    cargo build -q --manifest-path "$SRC/Cargo.toml" --features "$features" --target-dir "$WORK/lib-$features"
+    end=$(date +%s.%N)
+    echo "crate ${features:-default}: $(elapsed "$start" "$end")"
+done
+
+mkdir -p "$WORK/downstream/src"
+cat > "$WORK/downstream/Cargo.toml" <<EOF
+[package]
+name = "downstream"
+version = "0.0.0"
+edition = "2021"
+
+[dependencies]
+tuple_join = { path = "$SRC" }
+
+[workspace]
+EOF
+
+{
+    echo "use tuple_join::*;"
+    echo "pub fn run() {"
+    for l in $(seq 0 13); do
+        for r in $(seq 0 13); do
+            a=""; ta=""; b=""; tb=""
+            for i in $(seq 1 "$l"); do a="${a}${i}u8,"; ta="${ta}u8,"; done
+            for i in $(seq 1 "$r"); do b="${b}${i}u16,"; tb="${tb}u16,"; done
+            echo "    let _: (($ta), ($tb)) = ($a).join(($b)).split();"
+        done
+    done
+    echo "}"
+} > "$WORK/downstream/src/lib.rs"
+
+cd "$WORK/downstream"
+for cmd in check build; do
+    cargo "$cmd" -q
+    cargo clean -q -p downstream
+    start=$(date +%s.%N)
+    cargo "$cmd" -q
+    end=$(date +%s.%N)
+    echo "downstream $cmd (196 join/split pairs): $(elapsed "$start" "$end")"
+done
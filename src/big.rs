@@ -0,0 +1,181 @@
+//! A wrapper implementing common traits for tuples of any supported length.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use crate::cons::{ConsRefs, DefaultCons, IntoCons};
+use crate::Join;
+
+/// Format the elements of a cons list from its references.
+pub trait DebugCons: ConsRefs {
+    fn debug_cons(refs: Self::Ref<'_>, f: &mut fmt::DebugTuple);
+}
+
+impl DebugCons for () {
+    fn debug_cons(_: Self::Ref<'_>, _: &mut fmt::DebugTuple) {}
+}
+
+impl<H, T> DebugCons for (H, T) where H: fmt::Debug, T: DebugCons {
+    fn debug_cons(refs: Self::Ref<'_>, f: &mut fmt::DebugTuple) {
+        f.field(refs.0);
+        T::debug_cons(refs.1, f)
+    }
+}
+
+/// Compare the elements of two cons lists from their references.
+pub trait PartialEqCons: ConsRefs {
+    fn eq_cons(a: Self::Ref<'_>, b: Self::Ref<'_>) -> bool;
+}
+
+impl PartialEqCons for () {
+    fn eq_cons(_: Self::Ref<'_>, _: Self::Ref<'_>) -> bool {
+        true
+    }
+}
+
+impl<H, T> PartialEqCons for (H, T) where H: PartialEq, T: PartialEqCons {
+    fn eq_cons(a: Self::Ref<'_>, b: Self::Ref<'_>) -> bool {
+        a.0 == b.0 && T::eq_cons(a.1, b.1)
+    }
+}
+
+/// A cons list whose elements are all [`Eq`].
+pub trait EqCons: PartialEqCons {}
+
+impl EqCons for () {}
+
+impl<H, T> EqCons for (H, T) where H: Eq, T: EqCons {}
+
+/// Lexicographically compare the elements of two cons lists from their references.
+pub trait PartialOrdCons: PartialEqCons {
+    fn partial_cmp_cons(a: Self::Ref<'_>, b: Self::Ref<'_>) -> Option<Ordering>;
+}
+
+impl PartialOrdCons for () {
+    fn partial_cmp_cons(_: Self::Ref<'_>, _: Self::Ref<'_>) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+}
+
+impl<H, T> PartialOrdCons for (H, T) where H: PartialOrd, T: PartialOrdCons {
+    fn partial_cmp_cons(a: Self::Ref<'_>, b: Self::Ref<'_>) -> Option<Ordering> {
+        match a.0.partial_cmp(b.0) {
+            Some(Ordering::Equal) => T::partial_cmp_cons(a.1, b.1),
+            ordering => ordering,
+        }
+    }
+}
+
+/// Lexicographically compare the elements of two cons lists from their references.
+pub trait OrdCons: EqCons + PartialOrdCons {
+    fn cmp_cons(a: Self::Ref<'_>, b: Self::Ref<'_>) -> Ordering;
+}
+
+impl OrdCons for () {
+    fn cmp_cons(_: Self::Ref<'_>, _: Self::Ref<'_>) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl<H, T> OrdCons for (H, T) where H: Ord, T: OrdCons {
+    fn cmp_cons(a: Self::Ref<'_>, b: Self::Ref<'_>) -> Ordering {
+        a.0.cmp(b.0).then_with(|| T::cmp_cons(a.1, b.1))
+    }
+}
+
+/// Hash the elements of a cons list from its references.
+pub trait HashCons: ConsRefs {
+    fn hash_cons<S: Hasher>(refs: Self::Ref<'_>, state: &mut S);
+}
+
+impl HashCons for () {
+    fn hash_cons<S: Hasher>(_: Self::Ref<'_>, _: &mut S) {}
+}
+
+impl<H, T> HashCons for (H, T) where H: Hash, T: HashCons {
+    fn hash_cons<S: Hasher>(refs: Self::Ref<'_>, state: &mut S) {
+        refs.0.hash(state);
+        T::hash_cons(refs.1, state)
+    }
+}
+
+/// A transparent wrapper implementing `Debug`, `PartialEq`, `Eq`, `PartialOrd`,
+/// `Ord`, `Hash` and `Default` for tuples of any supported length,
+/// with the same behavior as `core`'s impls for short tuples.
+///
+/// ```
+/// use tuple_join::*;
+///
+/// let joined = Big((1, 2, 3, 4, 5, 6, 7)).join(Big((8, 9, 10, 11, 12, 13, 14)));
+/// assert_eq!(joined, Big((1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14)));
+/// assert_eq!(format!("{:?}", Big((1,))), "(1,)");
+/// assert_eq!(format!("{:?}", Big(())), "()");
+///
+/// let (a, b): (Big<(i32, i32, i32, i32, i32, i32, i32)>, Big<_>) = joined.split();
+/// assert!(a < b);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct Big<T>(pub T);
+
+impl<T> Big<T> {
+    /// Join with another wrapped tuple.
+    pub fn join<U>(self, other: Big<U>) -> Big<T::Out> where T: Join<U> {
+        Big(self.0.join(other.0))
+    }
+
+    /// Split into two wrapped tuples.
+    pub fn split<A, B>(self) -> (Big<A>, Big<B>) where A: Join<B, Out = T> {
+        let (a, b) = A::split(self.0);
+        (Big(a), Big(b))
+    }
+}
+
+impl<T> From<T> for Big<T> {
+    fn from(tuple: T) -> Self {
+        Big(tuple)
+    }
+}
+
+impl<T> fmt::Debug for Big<T> where T: IntoCons, T::Cons: DebugCons {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if T::LEN == 0 {
+            return f.write_str("()");
+        }
+        let mut tuple = f.debug_tuple("");
+        T::Cons::debug_cons(self.0.cons_ref(), &mut tuple);
+        tuple.finish()
+    }
+}
+
+impl<T> PartialEq for Big<T> where T: IntoCons, T::Cons: PartialEqCons {
+    fn eq(&self, other: &Self) -> bool {
+        T::Cons::eq_cons(self.0.cons_ref(), other.0.cons_ref())
+    }
+}
+
+impl<T> Eq for Big<T> where T: IntoCons, T::Cons: EqCons {}
+
+impl<T> PartialOrd for Big<T> where T: IntoCons, T::Cons: PartialOrdCons {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        T::Cons::partial_cmp_cons(self.0.cons_ref(), other.0.cons_ref())
+    }
+}
+
+impl<T> Ord for Big<T> where T: IntoCons, T::Cons: OrdCons {
+    fn cmp(&self, other: &Self) -> Ordering {
+        T::Cons::cmp_cons(self.0.cons_ref(), other.0.cons_ref())
+    }
+}
+
+impl<T> Hash for Big<T> where T: IntoCons, T::Cons: HashCons {
+    fn hash<S: Hasher>(&self, state: &mut S) {
+        T::Cons::hash_cons(self.0.cons_ref(), state)
+    }
+}
+
+impl<T> Default for Big<T> where T: IntoCons, T::Cons: DefaultCons {
+    fn default() -> Self {
+        Big(T::from_cons(T::Cons::default_cons()))
+    }
+}
@@ -0,0 +1,740 @@
+//! Right-nested cons list representation `(A, (B, (C, ())))` of tuples.
+//!
+//! Tuple operations are implemented once over the cons form, flat tuples
+//! only need a single conversion impl per length.
+
+use core::marker::PhantomData;
+use crate::{Apply, Deep, FoldFn, FromArray, IntoArray, Join, Leaf, PolyFn, Shallow, Tuple};
+
+/// Convert a flat tuple to and from its cons list.
+pub trait IntoCons: Tuple + Sized {
+    type Cons: ConsRefs;
+
+    fn into_cons(self) -> Self::Cons;
+    fn from_cons(cons: Self::Cons) -> Self;
+    fn cons_ref(&self) -> <Self::Cons as ConsRefs>::Ref<'_>;
+    fn cons_mut(&mut self) -> <Self::Cons as ConsRefs>::Mut<'_>;
+}
+
+/// Convert a cons list to and from its flat tuple.
+pub trait Cons: Sized {
+    type Tuple: Tuple;
+
+    fn into_tuple(self) -> Self::Tuple;
+    fn from_tuple(tuple: Self::Tuple) -> Self;
+}
+
+/// Cons lists of references to the elements of a cons list.
+pub trait ConsRefs {
+    type Ref<'a> where Self: 'a;
+    type Mut<'a> where Self: 'a;
+}
+
+impl ConsRefs for () {
+    type Ref<'a> = ();
+    type Mut<'a> = ();
+}
+
+impl<H, T> ConsRefs for (H, T) where T: ConsRefs {
+    type Ref<'a> = (&'a H, T::Ref<'a>) where Self: 'a;
+    type Mut<'a> = (&'a mut H, T::Mut<'a>) where Self: 'a;
+}
+
+/// Concatenate two cons lists.
+pub trait Concat<B>: Sized {
+    type Out;
+
+    fn concat(self, other: B) -> Self::Out;
+    fn split(cons: Self::Out) -> (Self, B);
+}
+
+impl<B> Concat<B> for () {
+    type Out = B;
+
+    fn concat(self, other: B) -> Self::Out {
+        other
+    }
+
+    fn split(cons: Self::Out) -> (Self, B) {
+        ((), cons)
+    }
+}
+
+impl<H, T, B> Concat<B> for (H, T) where T: Concat<B> {
+    type Out = (H, T::Out);
+
+    fn concat(self, other: B) -> Self::Out {
+        (self.0, self.1.concat(other))
+    }
+
+    fn split((head, tail): Self::Out) -> (Self, B) {
+        let (tail, other) = T::split(tail);
+        ((head, tail), other)
+    }
+}
+
+/// Reverse a cons list onto the accumulator `Acc`.
+pub trait ReverseOnto<Acc>: Sized {
+    type Out;
+
+    fn reverse_onto(self, acc: Acc) -> Self::Out;
+}
+
+impl<Acc> ReverseOnto<Acc> for () {
+    type Out = Acc;
+
+    fn reverse_onto(self, acc: Acc) -> Self::Out {
+        acc
+    }
+}
+
+impl<H, T, Acc> ReverseOnto<Acc> for (H, T) where T: ReverseOnto<(H, Acc)> {
+    type Out = T::Out;
+
+    fn reverse_onto(self, acc: Acc) -> Self::Out {
+        self.1.reverse_onto((self.0, acc))
+    }
+}
+
+/// Type level zero.
+pub struct Zero;
+
+/// Type level successor.
+pub struct Succ<N>(PhantomData<N>);
+
+/// A const index, converted to a type level number by [`ToPeano`].
+pub struct Index<const N: usize>;
+
+/// Map a const index to a type level number.
+pub trait ToPeano {
+    type Peano;
+}
+
+/// Split a cons list type after `N` elements, `N` being a type level number.
+pub trait SplitCons<N> {
+    type Left;
+    type Right;
+}
+
+impl<T> SplitCons<Zero> for T {
+    type Left = ();
+    type Right = T;
+}
+
+impl<N, H, T> SplitCons<Succ<N>> for (H, T) where T: SplitCons<N> {
+    type Left = (H, T::Left);
+    type Right = T::Right;
+}
+
+/// Get the element at `N` of a cons list, `N` being a type level number.
+pub trait GetCons<N>: ConsRefs + Sized {
+    type Output;
+
+    fn get_cons(self) -> Self::Output;
+    fn get_ref(refs: Self::Ref<'_>) -> &Self::Output;
+    fn get_mut(refs: Self::Mut<'_>) -> &mut Self::Output;
+}
+
+impl<H, T> GetCons<Zero> for (H, T) where T: ConsRefs {
+    type Output = H;
+
+    fn get_cons(self) -> Self::Output {
+        self.0
+    }
+
+    fn get_ref(refs: Self::Ref<'_>) -> &Self::Output {
+        refs.0
+    }
+
+    fn get_mut(refs: Self::Mut<'_>) -> &mut Self::Output {
+        refs.0
+    }
+}
+
+impl<N, H, T> GetCons<Succ<N>> for (H, T) where T: GetCons<N> {
+    type Output = T::Output;
+
+    fn get_cons(self) -> Self::Output {
+        self.1.get_cons()
+    }
+
+    fn get_ref(refs: Self::Ref<'_>) -> &Self::Output {
+        T::get_ref(refs.1)
+    }
+
+    fn get_mut(refs: Self::Mut<'_>) -> &mut Self::Output {
+        T::get_mut(refs.1)
+    }
+}
+
+/// Insert `X` at `N` of a cons list, `N` being a type level number.
+pub trait InsertCons<N, X> {
+    type Out;
+
+    fn insert_cons(self, x: X) -> Self::Out;
+}
+
+impl<T, X> InsertCons<Zero, X> for T {
+    type Out = (X, T);
+
+    fn insert_cons(self, x: X) -> Self::Out {
+        (x, self)
+    }
+}
+
+impl<N, H, T, X> InsertCons<Succ<N>, X> for (H, T) where T: InsertCons<N, X> {
+    type Out = (H, T::Out);
+
+    fn insert_cons(self, x: X) -> Self::Out {
+        (self.0, self.1.insert_cons(x))
+    }
+}
+
+/// Remove the element at `N` of a cons list, `N` being a type level number.
+pub trait RemoveCons<N> {
+    type Output;
+    type Out;
+
+    fn remove_cons(self) -> (Self::Output, Self::Out);
+}
+
+impl<H, T> RemoveCons<Zero> for (H, T) {
+    type Output = H;
+    type Out = T;
+
+    fn remove_cons(self) -> (Self::Output, Self::Out) {
+        self
+    }
+}
+
+impl<N, H, T> RemoveCons<Succ<N>> for (H, T) where T: RemoveCons<N> {
+    type Output = T::Output;
+    type Out = (H, T::Out);
+
+    fn remove_cons(self) -> (Self::Output, Self::Out) {
+        let (output, tail) = self.1.remove_cons();
+        (output, (self.0, tail))
+    }
+}
+
+/// Flatten a single tuple element into a cons list, with depth `D`.
+pub trait FlattenElem<D>: Sized {
+    type Cons;
+
+    fn flatten_elem(self) -> Self::Cons;
+    fn unflatten_elem(cons: Self::Cons) -> Self;
+}
+
+impl<D, E> FlattenElem<D> for E where E: Leaf {
+    type Cons = (E, ());
+
+    fn flatten_elem(self) -> Self::Cons {
+        (self, ())
+    }
+
+    fn unflatten_elem(cons: Self::Cons) -> Self {
+        cons.0
+    }
+}
+
+/// Flatten every element of a cons list, with depth `D`.
+pub trait FlattenCons<D>: Sized {
+    type Out;
+
+    fn flatten_cons(self) -> Self::Out;
+    fn unflatten_cons(cons: Self::Out) -> Self;
+}
+
+impl<D> FlattenCons<D> for () {
+    type Out = ();
+
+    fn flatten_cons(self) -> Self::Out {}
+
+    fn unflatten_cons(_: Self::Out) -> Self {}
+}
+
+impl<D, H, T> FlattenCons<D> for (H, T)
+where
+    H: FlattenElem<D>,
+    T: FlattenCons<D>,
+    H::Cons: Concat<T::Out>,
+{
+    type Out = <H::Cons as Concat<T::Out>>::Out;
+
+    fn flatten_cons(self) -> Self::Out {
+        self.0.flatten_elem().concat(self.1.flatten_cons())
+    }
+
+    fn unflatten_cons(cons: Self::Out) -> Self {
+        let (head, tail) = <H::Cons as Concat<T::Out>>::split(cons);
+        (H::unflatten_elem(head), T::unflatten_cons(tail))
+    }
+}
+
+/// Map every element of a cons list with a [`PolyFn`].
+pub trait MapCons<F> {
+    type Out;
+
+    fn map_cons(self, f: &mut F) -> Self::Out;
+}
+
+impl<F> MapCons<F> for () {
+    type Out = ();
+
+    fn map_cons(self, _: &mut F) -> Self::Out {}
+}
+
+impl<F, H, T> MapCons<F> for (H, T) where F: PolyFn<H>, T: MapCons<F> {
+    type Out = (F::Output, T::Out);
+
+    fn map_cons(self, f: &mut F) -> Self::Out {
+        let head = f.call(self.0);
+        (head, self.1.map_cons(f))
+    }
+}
+
+/// Fold every element of a cons list from the left with a [`FoldFn`].
+pub trait FoldCons<Acc, F> {
+    type Out;
+
+    fn fold_cons(self, acc: Acc, f: &mut F) -> Self::Out;
+}
+
+impl<Acc, F> FoldCons<Acc, F> for () {
+    type Out = Acc;
+
+    fn fold_cons(self, acc: Acc, _: &mut F) -> Self::Out {
+        acc
+    }
+}
+
+impl<Acc, F, H, T> FoldCons<Acc, F> for (H, T) where F: FoldFn<Acc, H>, T: FoldCons<F::Output, F> {
+    type Out = T::Out;
+
+    fn fold_cons(self, acc: Acc, f: &mut F) -> Self::Out {
+        let acc = f.call(acc, self.0);
+        self.1.fold_cons(acc, f)
+    }
+}
+
+/// Zip two cons lists of the same length into a cons list of pairs.
+pub trait ZipCons<U> {
+    type Out;
+
+    fn zip_cons(self, other: U) -> Self::Out;
+}
+
+impl ZipCons<()> for () {
+    type Out = ();
+
+    fn zip_cons(self, _: ()) -> Self::Out {}
+}
+
+impl<H, T, H2, T2> ZipCons<(H2, T2)> for (H, T) where T: ZipCons<T2> {
+    type Out = ((H, H2), T::Out);
+
+    fn zip_cons(self, other: (H2, T2)) -> Self::Out {
+        ((self.0, other.0), self.1.zip_cons(other.1))
+    }
+}
+
+/// Unzip a cons list of pairs into two cons lists.
+pub trait UnzipCons {
+    type Left;
+    type Right;
+
+    fn unzip_cons(self) -> (Self::Left, Self::Right);
+}
+
+impl UnzipCons for () {
+    type Left = ();
+    type Right = ();
+
+    fn unzip_cons(self) -> (Self::Left, Self::Right) {
+        ((), ())
+    }
+}
+
+impl<A, B, T> UnzipCons for ((A, B), T) where T: UnzipCons {
+    type Left = (A, T::Left);
+    type Right = (B, T::Right);
+
+    fn unzip_cons(self) -> (Self::Left, Self::Right) {
+        let ((a, b), tail) = self;
+        let (left, right) = tail.unzip_cons();
+        ((a, left), (b, right))
+    }
+}
+
+/// Transpose a cons list of options into an option of a cons list.
+pub trait TransposeOptionCons {
+    type Out;
+
+    fn transpose_cons(self) -> Option<Self::Out>;
+    fn untranspose_cons(option: Option<Self::Out>) -> Self;
+}
+
+impl TransposeOptionCons for () {
+    type Out = ();
+
+    fn transpose_cons(self) -> Option<Self::Out> {
+        Some(())
+    }
+
+    fn untranspose_cons(_: Option<Self::Out>) -> Self {}
+}
+
+impl<H, T> TransposeOptionCons for (Option<H>, T) where T: TransposeOptionCons {
+    type Out = (H, T::Out);
+
+    fn transpose_cons(self) -> Option<Self::Out> {
+        Some((self.0?, self.1.transpose_cons()?))
+    }
+
+    fn untranspose_cons(option: Option<Self::Out>) -> Self {
+        match option {
+            Some((head, tail)) => (Some(head), T::untranspose_cons(Some(tail))),
+            None => (None, T::untranspose_cons(None)),
+        }
+    }
+}
+
+/// Transpose a cons list of results into a result of a cons list.
+pub trait TransposeResultCons<E> {
+    type Out;
+
+    fn transpose_cons(self) -> Result<Self::Out, E>;
+    fn transpose_with_cons(self, index: usize, f: &mut impl FnMut(usize, E)) -> Option<Self::Out>;
+}
+
+impl<E> TransposeResultCons<E> for () {
+    type Out = ();
+
+    fn transpose_cons(self) -> Result<Self::Out, E> {
+        Ok(())
+    }
+
+    fn transpose_with_cons(self, _: usize, _: &mut impl FnMut(usize, E)) -> Option<Self::Out> {
+        Some(())
+    }
+}
+
+impl<H, T, E> TransposeResultCons<E> for (Result<H, E>, T) where T: TransposeResultCons<E> {
+    type Out = (H, T::Out);
+
+    fn transpose_cons(self) -> Result<Self::Out, E> {
+        Ok((self.0?, self.1.transpose_cons()?))
+    }
+
+    fn transpose_with_cons(self, index: usize, f: &mut impl FnMut(usize, E)) -> Option<Self::Out> {
+        let head = self.0.map_err(|e| f(index, e)).ok();
+        let tail = self.1.transpose_with_cons(index + 1, f);
+        Some((head?, tail?))
+    }
+}
+
+/// Create a cons list of default elements.
+pub trait DefaultCons {
+    fn default_cons() -> Self;
+}
+
+impl DefaultCons for () {
+    fn default_cons() -> Self {}
+}
+
+impl<H, T> DefaultCons for (H, T) where H: Default, T: DefaultCons {
+    fn default_cons() -> Self {
+        (H::default(), T::default_cons())
+    }
+}
+
+/// Convert a tuple, or a reference to a tuple, into the cons list of its elements
+/// or of references to them.
+pub trait IntoMapCons {
+    type Cons;
+
+    fn into_map_cons(self) -> Self::Cons;
+}
+
+impl<T> IntoMapCons for T where T: IntoCons {
+    type Cons = T::Cons;
+
+    fn into_map_cons(self) -> Self::Cons {
+        self.into_cons()
+    }
+}
+
+macro_rules! impl_map_ref {
+    ($($x: ident)*; $cons: tt; $refs: ty; $muts: ty) => {
+        #[allow(clippy::unused_unit)]
+        impl<'a, $($x,)*> IntoMapCons for &'a ($($x,)*) {
+            type Cons = $refs;
+
+            fn into_map_cons(self) -> Self::Cons {
+                let ($($x,)*) = self;
+                $cons
+            }
+        }
+
+        #[allow(clippy::unused_unit)]
+        impl<'a, $($x,)*> IntoMapCons for &'a mut ($($x,)*) {
+            type Cons = $muts;
+
+            fn into_map_cons(self) -> Self::Cons {
+                let ($($x,)*) = self;
+                $cons
+            }
+        }
+    };
+}
+
+/// Replaces an identifier with a type.
+macro_rules! replace {
+    ($x: ident, $ty: ty) => {$ty};
+}
+
+macro_rules! impl_array {
+    ($($x: ident)*; $len: tt) => {
+        impl<Item> IntoArray<Item, {$len}> for ($(replace!($x, Item),)*) {
+            fn into_array(self) -> [Item; $len] {
+                let ($($x,)*) = self;
+                [$($x,)*]
+            }
+
+            fn as_array_refs(&self) -> [&Item; $len] {
+                let ($($x,)*) = self;
+                [$($x,)*]
+            }
+        }
+
+        #[allow(clippy::unused_unit)]
+        impl<Item> FromArray<Item, {$len}> for ($(replace!($x, Item),)*) {
+            fn from_array(array: [Item; $len]) -> Self {
+                let [$($x,)*] = array;
+                ($($x,)*)
+            }
+        }
+    };
+}
+
+macro_rules! impl_apply {
+    ($($x: ident)*) => {
+        impl<Func, Ret, $($x,)*> Apply<Func> for ($($x,)*) where Func: FnOnce($($x),*) -> Ret {
+            type Output = Ret;
+
+            fn apply(self, f: Func) -> Self::Output {
+                let ($($x,)*) = self;
+                f($($x),*)
+            }
+        }
+    };
+}
+
+/// `Join` directly on flat tuples, for a left tuple of `$n` elements.
+macro_rules! impl_join {
+    ($($x: ident)*; $n: literal; $len: literal; $($y: ident)*) => {
+        #[allow(clippy::unused_unit)]
+        impl<$($x,)* $($y,)*> Join<($($y,)*)> for ($($x,)*) {
+            type Out = ($($x,)* $($y,)*);
+            const LEFT_LEN: usize = $n;
+            const RIGHT_LEN: usize = $len - $n;
+
+            fn join(self, ($($y,)*): ($($y,)*)) -> Self::Out {
+                let ($($x,)*) = self;
+                ($($x,)* $($y,)*)
+            }
+
+            fn split(($($x,)* $($y,)*): Self::Out) -> (Self, ($($y,)*)) {
+                (($($x,)*), ($($y,)*))
+            }
+        }
+    };
+}
+
+/// The split points of a tuple with up to 13 elements on the left, moving one
+/// identifier at a time to the left. Longer left tuples join through the cons form.
+macro_rules! tuple_join {
+    ($($x: ident)*; $n: literal $($ns: literal)*; $len: literal;) => {
+        impl_join!($($x)*; $n; $len;);
+    };
+    ($($x: ident)*; $n: literal; $len: literal; $($y: ident)*) => {
+        impl_join!($($x)*; $n; $len; $($y)*);
+    };
+    ($($x: ident)*; $n: literal $($ns: literal)*; $len: literal; $y0: ident $($y: ident)*) => {
+        impl_join!($($x)*; $n; $len; $y0 $($y)*);
+        tuple_join!($($x)* $y0; $($ns)*; $len; $($y)*);
+    };
+}
+
+/// `Join` for a left tuple of `$len` elements onto any right tuple, through the cons
+/// form of the right tuple.
+macro_rules! impl_join_cons {
+    ($($x: ident)*; $tail: ident $onto: tt; $len: literal) => {
+        impl<$tail, Right, $($x,)*> Join<Right> for ($($x,)*) where Right: IntoCons<Cons = $tail>, $onto: Cons {
+            type Out = <$onto as Cons>::Tuple;
+            const LEFT_LEN: usize = $len;
+            const RIGHT_LEN: usize = Right::LEN;
+
+            fn join(self, other: Right) -> Self::Out {
+                let ($($x,)*) = self;
+                let $tail = other.into_cons();
+                Cons::into_tuple($onto)
+            }
+
+            fn split(tuple: Self::Out) -> (Self, Right) {
+                let $onto = Cons::from_tuple(tuple);
+                (($($x,)*), Right::from_cons($tail))
+            }
+        }
+    };
+}
+
+macro_rules! impl_cons {
+    ($($x: ident)*; $cons: tt; $tail: ident $onto: tt; $refs: tt; $muts: tt; $len: tt; $peano: ty) => {
+        #[allow(clippy::unused_unit)]
+        impl<$($x,)*> IntoCons for ($($x,)*) {
+            type Cons = $cons;
+
+            fn into_cons(self) -> Self::Cons {
+                let ($($x,)*) = self;
+                $cons
+            }
+
+            fn from_cons($cons: Self::Cons) -> Self {
+                ($($x,)*)
+            }
+
+            fn cons_ref(&self) -> <Self::Cons as ConsRefs>::Ref<'_> {
+                let ($($x,)*) = self;
+                $cons
+            }
+
+            fn cons_mut(&mut self) -> <Self::Cons as ConsRefs>::Mut<'_> {
+                let ($($x,)*) = self;
+                $cons
+            }
+        }
+
+        #[allow(clippy::unused_unit)]
+        impl<$($x,)*> Cons for $cons {
+            type Tuple = ($($x,)*);
+
+            fn into_tuple(self) -> Self::Tuple {
+                let $cons = self;
+                ($($x,)*)
+            }
+
+            fn from_tuple(($($x,)*): Self::Tuple) -> Self {
+                $cons
+            }
+        }
+
+        impl<$($x,)*> FlattenElem<Shallow> for ($($x,)*) {
+            type Cons = $cons;
+
+            fn flatten_elem(self) -> Self::Cons {
+                self.into_cons()
+            }
+
+            fn unflatten_elem(cons: Self::Cons) -> Self {
+                Self::from_cons(cons)
+            }
+        }
+
+        impl<$($x,)*> FlattenElem<Deep> for ($($x,)*) where $cons: FlattenCons<Deep> {
+            type Cons = <$cons as FlattenCons<Deep>>::Out;
+
+            fn flatten_elem(self) -> Self::Cons {
+                FlattenCons::<Deep>::flatten_cons(self.into_cons())
+            }
+
+            fn unflatten_elem(cons: Self::Cons) -> Self {
+                Self::from_cons(<$cons as FlattenCons<Deep>>::unflatten_cons(cons))
+            }
+        }
+
+        impl<$($x,)*> Tuple for ($($x,)*) {
+            const LEN: usize = $len;
+        }
+
+        impl<Ret, $($x,)*> Leaf for fn($($x),*) -> Ret {}
+
+        impl_map_ref!($($x)*; $cons; $refs; $muts);
+        impl_array!($($x)*; $len);
+        impl_apply!($($x)*);
+        tuple_join!(; 0 1 2 3 4 5 6 7 8 9 10 11 12 13; $len; $($x)*);
+
+        impl ToPeano for Index<{$len}> {
+            type Peano = $peano;
+        }
+    };
+}
+
+/// Builds tuples by prepending one identifier at a time, carrying the cons lists of
+/// elements, onto `Tail` and of references, and the type level length along,
+/// and taking the next length. Lengths after `|` also get [`impl_join_cons`].
+macro_rules! tuple_cons {
+    ($($x: ident)*; $cons: tt; $tail: ident $onto: tt; $refs: tt; $muts: tt; $peano: ty; $len: literal $($lens: literal)* | $($long: literal)*; $y0: ident $($y: ident)*) => {
+        impl_cons!($($x)*; $cons; $tail $onto; $refs; $muts; $len; $peano);
+        tuple_cons!(
+            $y0 $($x)*;
+            ($y0, $cons);
+            $tail ($y0, $onto);
+            (&'a $y0, $refs);
+            (&'a mut $y0, $muts);
+            Succ<$peano>;
+            $($lens)* | $($long)*;
+            $($y)*
+        );
+    };
+    ($($x: ident)*; $cons: tt; $tail: ident $onto: tt; $refs: tt; $muts: tt; $peano: ty; | $len: literal;) => {
+        impl_cons!($($x)*; $cons; $tail $onto; $refs; $muts; $len; $peano);
+        impl_join_cons!($($x)*; $tail $onto; $len);
+    };
+    ($($x: ident)*; $cons: tt; $tail: ident $onto: tt; $refs: tt; $muts: tt; $peano: ty; | $len: literal $($lens: literal)*; $y0: ident $($y: ident)*) => {
+        impl_cons!($($x)*; $cons; $tail $onto; $refs; $muts; $len; $peano);
+        impl_join_cons!($($x)*; $tail $onto; $len);
+        tuple_cons!(
+            $y0 $($x)*;
+            ($y0, $cons);
+            $tail ($y0, $onto);
+            (&'a $y0, $refs);
+            (&'a mut $y0, $muts);
+            Succ<$peano>;
+            | $($lens)*;
+            $($y)*
+        );
+    };
+}
+
+#[cfg(not(feature = "arity-16"))]
+tuple_cons!(; (); Tail Tail; (); (); Zero;
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 | 14 15 16 17 18 19 20 21 22 23 24 25 26;
+    A B C D E F G H I J K L M N O P Q R S T U V W X Y Z
+);
+
+#[cfg(all(feature = "arity-16", not(feature = "arity-24")))]
+tuple_cons!(; (); Tail Tail; (); (); Zero;
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 | 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+    32;
+    A B C D E F G H I J K L M N O P Q R S T U V W X Y Z
+    A1 B1 C1 D1 E1 F1
+);
+
+#[cfg(all(feature = "arity-24", not(feature = "arity-32")))]
+tuple_cons!(; (); Tail Tail; (); (); Zero;
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 | 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+    32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48;
+    A B C D E F G H I J K L M N O P Q R S T U V W X Y Z
+    A1 B1 C1 D1 E1 F1 G1 H1 I1 J1 K1 L1 M1 N1 O1 P1 Q1 R1 S1 T1 U1 V1
+);
+
+#[cfg(feature = "arity-32")]
+tuple_cons!(; (); Tail Tail; (); (); Zero;
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 | 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+    32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+    64;
+    A B C D E F G H I J K L M N O P Q R S T U V W X Y Z
+    A1 B1 C1 D1 E1 F1 G1 H1 I1 J1 K1 L1 M1 N1 O1 P1 Q1 R1 S1 T1 U1 V1 W1 X1 Y1 Z1
+    A2 B2 C2 D2 E2 F2 G2 H2 I2 J2 K2 L2
+);
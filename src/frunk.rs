@@ -0,0 +1,72 @@
+//! Conversions between tuples and frunk's `HList`, enabled by the `frunk` feature.
+
+use frunk::hlist::{HCons, HNil};
+use crate::cons::IntoCons;
+
+/// Convert a cons list to and from a frunk `HList`.
+pub trait ConsFrunk: Sized {
+    type HList;
+
+    fn into_frunk(self) -> Self::HList;
+    fn from_frunk(hlist: Self::HList) -> Self;
+}
+
+impl ConsFrunk for () {
+    type HList = HNil;
+
+    fn into_frunk(self) -> Self::HList {
+        HNil
+    }
+
+    fn from_frunk(_: Self::HList) -> Self {}
+}
+
+impl<H, T> ConsFrunk for (H, T) where T: ConsFrunk {
+    type HList = HCons<H, T::HList>;
+
+    fn into_frunk(self) -> Self::HList {
+        HCons { head: self.0, tail: self.1.into_frunk() }
+    }
+
+    fn from_frunk(hlist: Self::HList) -> Self {
+        (hlist.head, T::from_frunk(hlist.tail))
+    }
+}
+
+/// Convert a tuple type into a frunk `HList`.
+/// 
+/// Joining tuples is consistent with concatenating `HList`s with `+`.
+/// 
+/// ```
+/// use tuple_join::*;
+/// use frunk::hlist;
+/// 
+/// let (a, b) = ((1, "a"), (2.0,));
+/// assert_eq!(a.into_frunk(), hlist![1, "a"]);
+/// assert_eq!(a.join(b).into_frunk(), a.into_frunk() + b.into_frunk());
+/// assert_eq!(<(i32, &str)>::from_frunk(hlist![1, "a"]), (1, "a"));
+/// ```
+pub trait IntoFrunk {
+    type HList;
+
+    fn into_frunk(self) -> Self::HList where Self: Sized;
+}
+
+/// Convert a frunk `HList` into a tuple type.
+pub trait FromFrunk: IntoFrunk {
+    fn from_frunk(hlist: Self::HList) -> Self where Self: Sized;
+}
+
+impl<T> IntoFrunk for T where T: IntoCons, T::Cons: ConsFrunk {
+    type HList = <T::Cons as ConsFrunk>::HList;
+
+    fn into_frunk(self) -> Self::HList {
+        self.into_cons().into_frunk()
+    }
+}
+
+impl<T> FromFrunk for T where T: IntoCons, T::Cons: ConsFrunk {
+    fn from_frunk(hlist: Self::HList) -> Self {
+        T::from_cons(ConsFrunk::from_frunk(hlist))
+    }
+}
@@ -0,0 +1,256 @@
+//! Joining tuples of futures, built on `core::task` only.
+
+use core::future::Future;
+use core::marker::PhantomData;
+use core::mem;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use crate::cons::{Cons, IntoCons, TransposeResultCons};
+
+/// A future that keeps its output until taken.
+pub enum MaybeDone<F: Future> {
+    Future(F),
+    Done(F::Output),
+    Taken,
+}
+
+impl<F: Future> MaybeDone<F> {
+    /// Poll the future if not done, returns `true` if done.
+    fn poll_done(self: Pin<&mut Self>, cx: &mut Context<'_>) -> bool {
+        // SAFETY: the future is pinned in place and never moved out of `self`.
+        let this = unsafe { self.get_unchecked_mut() };
+        match this {
+            MaybeDone::Future(future) => match unsafe { Pin::new_unchecked(future) }.poll(cx) {
+                Poll::Ready(output) => {
+                    *this = MaybeDone::Done(output);
+                    true
+                },
+                Poll::Pending => false,
+            },
+            MaybeDone::Done(_) => true,
+            MaybeDone::Taken => panic!("future polled after completion"),
+        }
+    }
+
+    /// The output, if done.
+    fn get_done(self: Pin<&mut Self>) -> Option<&F::Output> {
+        // SAFETY: only the output is borrowed, which is not pinned.
+        match unsafe { self.get_unchecked_mut() } {
+            MaybeDone::Done(output) => Some(output),
+            _ => None,
+        }
+    }
+
+    /// Take the output, must be done.
+    fn take(self: Pin<&mut Self>) -> F::Output {
+        // SAFETY: only replaced once done, the output is not pinned and there is no future left.
+        let this = unsafe { self.get_unchecked_mut() };
+        if !matches!(this, MaybeDone::Done(_)) {
+            panic!("future taken before completion");
+        }
+        match mem::replace(this, MaybeDone::Taken) {
+            MaybeDone::Done(output) => output,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Wrap every future of a cons list in [`MaybeDone`].
+pub trait IntoMaybeDoneCons {
+    type Out: PollCons;
+
+    fn into_maybe_done(self) -> Self::Out;
+}
+
+impl IntoMaybeDoneCons for () {
+    type Out = ();
+
+    fn into_maybe_done(self) -> Self::Out {}
+}
+
+impl<H, T> IntoMaybeDoneCons for (H, T) where H: Future, T: IntoMaybeDoneCons {
+    type Out = (MaybeDone<H>, T::Out);
+
+    fn into_maybe_done(self) -> Self::Out {
+        (MaybeDone::Future(self.0), self.1.into_maybe_done())
+    }
+}
+
+/// Poll every future of a pinned cons list of [`MaybeDone`].
+pub trait PollCons {
+    type Output;
+
+    /// Poll every future not done, returns `true` if all are done.
+    fn poll_cons(self: Pin<&mut Self>, cx: &mut Context<'_>) -> bool;
+    fn take_cons(self: Pin<&mut Self>) -> Self::Output;
+}
+
+/// Take the first error of a pinned cons list of [`MaybeDone`] with result outputs.
+pub trait TryPollCons<E>: PollCons {
+    fn take_err_cons(self: Pin<&mut Self>) -> Option<E>;
+}
+
+impl PollCons for () {
+    type Output = ();
+
+    fn poll_cons(self: Pin<&mut Self>, _: &mut Context<'_>) -> bool {
+        true
+    }
+
+    fn take_cons(self: Pin<&mut Self>) -> Self::Output {}
+}
+
+impl<E> TryPollCons<E> for () {
+    fn take_err_cons(self: Pin<&mut Self>) -> Option<E> {
+        None
+    }
+}
+
+/// Project a pinned cons list onto its head and tail.
+fn project<H, T>(cons: Pin<&mut (H, T)>) -> (Pin<&mut H>, Pin<&mut T>) {
+    // SAFETY: both fields are structurally pinned, neither is moved out.
+    unsafe {
+        let (head, tail) = cons.get_unchecked_mut();
+        (Pin::new_unchecked(head), Pin::new_unchecked(tail))
+    }
+}
+
+impl<F, T> PollCons for (MaybeDone<F>, T) where F: Future, T: PollCons {
+    type Output = (F::Output, T::Output);
+
+    fn poll_cons(self: Pin<&mut Self>, cx: &mut Context<'_>) -> bool {
+        let (head, tail) = project(self);
+        head.poll_done(cx) & tail.poll_cons(cx)
+    }
+
+    fn take_cons(self: Pin<&mut Self>) -> Self::Output {
+        let (head, tail) = project(self);
+        (head.take(), tail.take_cons())
+    }
+}
+
+impl<F, T, H, E> TryPollCons<E> for (MaybeDone<F>, T) where F: Future<Output = Result<H, E>>, T: TryPollCons<E> {
+    fn take_err_cons(self: Pin<&mut Self>) -> Option<E> {
+        let (mut head, tail) = project(self);
+        match head.as_mut().get_done() {
+            Some(Err(_)) => head.take().err(),
+            _ => tail.take_err_cons(),
+        }
+    }
+}
+
+/// Join a tuple of futures into a future of a tuple of their outputs.
+///
+/// Named `join_futures` since tuples are already [`Join`](crate::Join)able,
+/// futures are polled in order until all are done.
+///
+/// ```
+/// use core::future::{pending, ready, Future};
+/// use core::pin::{pin, Pin};
+/// use core::task::{Context, Poll, Waker};
+/// use tuple_join::*;
+///
+/// fn block_on<F: Future>(future: F) -> F::Output {
+///     let mut future = pin!(future);
+///     let mut cx = Context::from_waker(Waker::noop());
+///     loop {
+///         if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+///             return output;
+///         }
+///     }
+/// }
+///
+/// /// Pending the given number of times before being ready.
+/// struct Yield(u32);
+///
+/// impl Future for Yield {
+///     type Output = u32;
+///
+///     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+///         if self.0 == 0 {
+///             return Poll::Ready(0);
+///         }
+///         self.0 -= 1;
+///         cx.waker().wake_by_ref();
+///         Poll::Pending
+///     }
+/// }
+///
+/// let futures = (ready(1), async { 'b' }).join((ready("c"),));
+/// let output = block_on(futures.join_futures());
+/// assert_eq!(output, (1, 'b', "c"));
+///
+/// let yielding = (Yield(3), async { Yield(1).await + 1 }, Yield(0));
+/// assert_eq!(block_on(yielding.join_futures()), (0, 1, 0));
+/// assert_eq!(output.pop(), ((1, 'b'), "c"));
+///
+/// let results = (ready(Ok::<i32, &str>(1)), async { Ok::<char, &str>('b') });
+/// assert_eq!(block_on(results.try_join_futures()), Ok((1, 'b')));
+///
+/// let failing = (pending::<Result<i32, &str>>(), ready(Err::<char, _>("b")));
+/// assert_eq!(block_on(failing.try_join_futures()), Err("b"));
+/// ```
+pub trait JoinFutures {
+    type MaybeDone: PollCons;
+
+    fn join_futures(self) -> JoinAll<Self::MaybeDone> where Self: Sized;
+
+    /// Resolves to the first error of any output.
+    fn try_join_futures<E>(self) -> TryJoinAll<Self::MaybeDone, E> where Self: Sized, Self::MaybeDone: TryPollCons<E>;
+}
+
+impl<T> JoinFutures for T where T: IntoCons, T::Cons: IntoMaybeDoneCons {
+    type MaybeDone = <T::Cons as IntoMaybeDoneCons>::Out;
+
+    fn join_futures(self) -> JoinAll<Self::MaybeDone> {
+        JoinAll(self.into_cons().into_maybe_done())
+    }
+
+    fn try_join_futures<E>(self) -> TryJoinAll<Self::MaybeDone, E> where Self::MaybeDone: TryPollCons<E> {
+        TryJoinAll(self.into_cons().into_maybe_done(), PhantomData)
+    }
+}
+
+/// Future returned by [`JoinFutures::join_futures`].
+#[must_use = "futures do nothing unless polled"]
+pub struct JoinAll<C>(C);
+
+impl<C> Future for JoinAll<C> where C: PollCons, C::Output: Cons {
+    type Output = <C::Output as Cons>::Tuple;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: the cons list is structurally pinned.
+        let mut cons = unsafe { self.map_unchecked_mut(|this| &mut this.0) };
+        if cons.as_mut().poll_cons(cx) {
+            Poll::Ready(cons.take_cons().into_tuple())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Future returned by [`JoinFutures::try_join_futures`].
+#[must_use = "futures do nothing unless polled"]
+pub struct TryJoinAll<C, E>(C, PhantomData<fn() -> E>);
+
+impl<C, E> Future for TryJoinAll<C, E>
+where
+    C: TryPollCons<E>,
+    C::Output: TransposeResultCons<E>,
+    <C::Output as TransposeResultCons<E>>::Out: Cons,
+{
+    type Output = Result<<<C::Output as TransposeResultCons<E>>::Out as Cons>::Tuple, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: the cons list is structurally pinned.
+        let mut cons = unsafe { self.map_unchecked_mut(|this| &mut this.0) };
+        let done = cons.as_mut().poll_cons(cx);
+        if let Some(err) = cons.as_mut().take_err_cons() {
+            Poll::Ready(Err(err))
+        } else if done {
+            Poll::Ready(cons.take_cons().transpose_cons().map(Cons::into_tuple))
+        } else {
+            Poll::Pending
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//! Zipping tuples of iterators and unzipping iterators of tuples.
+
+use core::cmp;
+use core::iter::Fuse;
+use crate::cons::{Cons, ConsRefs, DefaultCons, IntoCons};
+
+type SizeHint = (usize, Option<usize>);
+
+/// Convert a cons list of `IntoIterator`s into a cons list of iterators.
+pub trait IntoIterCons {
+    type Iters: NextCons;
+    type Fused: NextLongestCons;
+
+    fn into_iter_cons(self) -> Self::Iters;
+    fn into_fused_cons(self) -> Self::Fused;
+}
+
+impl IntoIterCons for () {
+    type Iters = ();
+    type Fused = ();
+
+    fn into_iter_cons(self) -> Self::Iters {}
+
+    fn into_fused_cons(self) -> Self::Fused {}
+}
+
+impl<H, T> IntoIterCons for (H, T) where H: IntoIterator, T: IntoIterCons {
+    type Iters = (H::IntoIter, T::Iters);
+    type Fused = (Fuse<H::IntoIter>, T::Fused);
+
+    fn into_iter_cons(self) -> Self::Iters {
+        (self.0.into_iter(), self.1.into_iter_cons())
+    }
+
+    fn into_fused_cons(self) -> Self::Fused {
+        (self.0.into_iter().fuse(), self.1.into_fused_cons())
+    }
+}
+
+/// Advance every iterator of a cons list, stopping at the shortest.
+pub trait NextCons {
+    type Item;
+
+    /// Whether the cons list has no iterators. `()` yields `Some(())` to end longer lists,
+    /// so zipping it on its own has to check this instead.
+    const EMPTY: bool;
+
+    fn next_cons(&mut self) -> Option<Self::Item>;
+    fn size_hint_cons(&self) -> SizeHint;
+}
+
+impl NextCons for () {
+    type Item = ();
+    const EMPTY: bool = true;
+
+    fn next_cons(&mut self) -> Option<Self::Item> {
+        Some(())
+    }
+
+    fn size_hint_cons(&self) -> SizeHint {
+        (usize::MAX, None)
+    }
+}
+
+impl<H, T> NextCons for (H, T) where H: Iterator, T: NextCons {
+    type Item = (H::Item, T::Item);
+    const EMPTY: bool = false;
+
+    fn next_cons(&mut self) -> Option<Self::Item> {
+        Some((self.0.next()?, self.1.next_cons()?))
+    }
+
+    fn size_hint_cons(&self) -> SizeHint {
+        let (lower, upper) = self.0.size_hint();
+        let (tail_lower, tail_upper) = self.1.size_hint_cons();
+        let upper = match (upper, tail_upper) {
+            (Some(a), Some(b)) => Some(cmp::min(a, b)),
+            (a, b) => a.or(b),
+        };
+        (cmp::min(lower, tail_lower), upper)
+    }
+}
+
+/// Advance every iterator of a cons list, stopping at the longest.
+pub trait NextLongestCons {
+    type Item;
+
+    /// Returns the next items and whether any of them is `Some`.
+    fn next_longest_cons(&mut self) -> (Self::Item, bool);
+    fn size_hint_cons(&self) -> SizeHint;
+}
+
+impl NextLongestCons for () {
+    type Item = ();
+
+    fn next_longest_cons(&mut self) -> (Self::Item, bool) {
+        ((), false)
+    }
+
+    fn size_hint_cons(&self) -> SizeHint {
+        (0, Some(0))
+    }
+}
+
+impl<H, T> NextLongestCons for (H, T) where H: Iterator, T: NextLongestCons {
+    type Item = (Option<H::Item>, T::Item);
+
+    fn next_longest_cons(&mut self) -> (Self::Item, bool) {
+        let head = self.0.next();
+        let (tail, any) = self.1.next_longest_cons();
+        let any = any || head.is_some();
+        ((head, tail), any)
+    }
+
+    fn size_hint_cons(&self) -> SizeHint {
+        let (lower, upper) = self.0.size_hint();
+        let (tail_lower, tail_upper) = self.1.size_hint_cons();
+        let upper = match (upper, tail_upper) {
+            (Some(a), Some(b)) => Some(cmp::max(a, b)),
+            _ => None,
+        };
+        (cmp::max(lower, tail_lower), upper)
+    }
+}
+
+/// Extend every collection of a cons list with the matching item.
+pub trait ExtendCons<Items>: ConsRefs {
+    fn extend_cons(refs: Self::Mut<'_>, items: Items);
+}
+
+impl ExtendCons<()> for () {
+    fn extend_cons(_: Self::Mut<'_>, _: ()) {}
+}
+
+impl<C, T, H, I> ExtendCons<(H, I)> for (C, T) where C: Extend<H>, T: ExtendCons<I> {
+    fn extend_cons(refs: Self::Mut<'_>, items: (H, I)) {
+        refs.0.extend(Some(items.0));
+        T::extend_cons(refs.1, items.1)
+    }
+}
+
+/// Zip a tuple of `IntoIterator`s into an iterator of tuples.
+///
+/// Zipping the empty tuple yields nothing, with either method.
+///
+/// ```
+/// use tuple_join::*;
+///
+/// let rows: Vec<_> = ([1, 2, 3], "ab".chars(), vec![true, false]).zip_all().collect();
+/// assert_eq!(rows, [(1, 'a', true), (2, 'b', false)]);
+///
+/// let rows: Vec<_> = ([1, 2, 3], "ab".chars()).zip_longest().collect();
+/// assert_eq!(rows, [(Some(1), Some('a')), (Some(2), Some('b')), (Some(3), None)]);
+///
+/// assert_eq!(().zip_all().size_hint(), (0, Some(0)));
+/// assert_eq!(().zip_all().next(), None);
+/// assert_eq!(().zip_longest().next(), None);
+///
+/// let (xs, ys, zs): (Vec<i32>, String, Vec<bool>) = [(1, 'a', true), (2, 'b', false)].into_iter().unzip_all();
+/// assert_eq!((xs, ys.as_str(), zs), (vec![1, 2], "ab", vec![true, false]));
+/// ```
+pub trait ZipAll {
+    type Iters: NextCons;
+    type Fused: NextLongestCons;
+
+    /// Stops at the shortest iterator.
+    fn zip_all(self) -> ZipAllIter<Self::Iters> where Self: Sized;
+
+    /// Stops at the longest iterator, yielding `None` for exhausted ones.
+    fn zip_longest(self) -> ZipLongestIter<Self::Fused> where Self: Sized;
+}
+
+impl<T> ZipAll for T where T: IntoCons, T::Cons: IntoIterCons {
+    type Iters = <T::Cons as IntoIterCons>::Iters;
+    type Fused = <T::Cons as IntoIterCons>::Fused;
+
+    fn zip_all(self) -> ZipAllIter<Self::Iters> {
+        ZipAllIter(self.into_cons().into_iter_cons())
+    }
+
+    fn zip_longest(self) -> ZipLongestIter<Self::Fused> {
+        ZipLongestIter(self.into_cons().into_fused_cons())
+    }
+}
+
+/// Iterator returned by [`ZipAll::zip_all`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ZipAllIter<C>(C);
+
+impl<C> Iterator for ZipAllIter<C> where C: NextCons, C::Item: Cons {
+    type Item = <C::Item as Cons>::Tuple;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if C::EMPTY {
+            return None;
+        }
+        self.0.next_cons().map(Cons::into_tuple)
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        if C::EMPTY {
+            return (0, Some(0));
+        }
+        self.0.size_hint_cons()
+    }
+}
+
+/// Iterator returned by [`ZipAll::zip_longest`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ZipLongestIter<C>(C);
+
+impl<C> Iterator for ZipLongestIter<C> where C: NextLongestCons, C::Item: Cons {
+    type Item = <C::Item as Cons>::Tuple;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (items, any) = self.0.next_longest_cons();
+        any.then(|| items.into_tuple())
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.0.size_hint_cons()
+    }
+}
+
+/// Unzip an iterator of tuples into a tuple of `Extend` collections.
+pub trait UnzipAll: Iterator {
+    fn unzip_all<C>(self) -> C
+    where
+        Self: Sized,
+        Self::Item: IntoCons,
+        C: IntoCons,
+        C::Cons: DefaultCons + ExtendCons<<Self::Item as IntoCons>::Cons>,
+    {
+        let mut collections = C::from_cons(C::Cons::default_cons());
+        for item in self {
+            C::Cons::extend_cons(collections.cons_mut(), item.into_cons());
+        }
+        collections
+    }
+}
+
+impl<I> UnzipAll for I where I: Iterator {}
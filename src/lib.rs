@@ -2,7 +2,105 @@
 #![allow(nonstandard_style)]
 //! A crate for joining tuples at the type level.
 //! 
-//! Supports up to tuple length 13.
+//! Supports up to tuple length 26 by default, i.e. joining two tuples of length 13.
+//! 
+//! # Features
+//! 
+//! The maximum tuple length can be raised to 32, 48 and 64 with the `arity-16`,
+//! `arity-24` and `arity-32` features, at the cost of compile time.
+//! 
+//! ```
+//! # #[cfg(feature = "arity-16")] {
+//! use tuple_join::*;
+//! 
+//! type T8 = (u8, u8, u8, u8, u8, u8, u8, u8);
+//! type T16 = <T8 as Join<T8>>::Out;
+//! 
+//! let t8: T8 = (0, 1, 2, 3, 4, 5, 6, 7);
+//! let t16: T16 = t8.join(t8);
+//! let t32 = t16.join(t16);
+//! let expected: [u8; 32] = core::array::from_fn(|i| i as u8 % 8);
+//! assert_eq!(t32.into_array(), expected);
+//! 
+//! let (left, right): (T16, T16) = t32.split();
+//! assert_eq!(left.join(right).into_array(), expected);
+//! let (left, right) = t32.split_at::<5>();
+//! assert_eq!(left.into_array()[..], expected[..5]);
+//! assert_eq!(right.into_array()[..], expected[5..]);
+//! let (left, right) = t32.split_at::<16>();
+//! assert_eq!(left.into_array()[..], expected[..16]);
+//! assert_eq!(right.into_array()[..], expected[16..]);
+//! let (left, right) = t32.split_at::<27>();
+//! assert_eq!(left.into_array()[..], expected[..27]);
+//! assert_eq!(right.into_array()[..], expected[27..]);
+//! # }
+//! ```
+//! 
+//! ```
+//! # #[cfg(feature = "arity-24")] {
+//! use tuple_join::*;
+//! 
+//! type T8 = (u8, u8, u8, u8, u8, u8, u8, u8);
+//! type T24 = <<T8 as Join<T8>>::Out as Join<T8>>::Out;
+//! 
+//! let t8: T8 = (0, 1, 2, 3, 4, 5, 6, 7);
+//! let t24: T24 = t8.join(t8).join(t8);
+//! let t48 = t24.join(t24);
+//! let expected: [u8; 48] = core::array::from_fn(|i| i as u8 % 8);
+//! assert_eq!(t48.into_array(), expected);
+//! 
+//! let (left, right): (T24, T24) = t48.split();
+//! assert_eq!(left.join(right).into_array(), expected);
+//! let (left, right) = t48.split_at::<13>();
+//! assert_eq!(left.into_array()[..], expected[..13]);
+//! assert_eq!(right.into_array()[..], expected[13..]);
+//! let (left, right) = t48.split_at::<24>();
+//! assert_eq!(left.into_array()[..], expected[..24]);
+//! assert_eq!(right.into_array()[..], expected[24..]);
+//! let (left, right) = t48.split_at::<40>();
+//! assert_eq!(left.into_array()[..], expected[..40]);
+//! assert_eq!(right.into_array()[..], expected[40..]);
+//! # }
+//! ```
+//! 
+//! ```
+//! # #[cfg(feature = "arity-32")] {
+//! use tuple_join::*;
+//! 
+//! type T8 = (u8, u8, u8, u8, u8, u8, u8, u8);
+//! type T16 = <T8 as Join<T8>>::Out;
+//! type T32 = <T16 as Join<T16>>::Out;
+//! 
+//! let t8: T8 = (0, 1, 2, 3, 4, 5, 6, 7);
+//! let t16: T16 = t8.join(t8);
+//! let t32: T32 = t16.join(t16);
+//! let t64 = t32.join(t32);
+//! let expected: [u8; 64] = core::array::from_fn(|i| i as u8 % 8);
+//! assert_eq!(t64.into_array(), expected);
+//! 
+//! let (left, right): (T32, T32) = t64.split();
+//! assert_eq!(left.join(right).into_array(), expected);
+//! let (left, right) = t64.split_at::<13>();
+//! assert_eq!(left.into_array()[..], expected[..13]);
+//! assert_eq!(right.into_array()[..], expected[13..]);
+//! let (left, right) = t64.split_at::<32>();
+//! assert_eq!(left.into_array()[..], expected[..32]);
+//! assert_eq!(right.into_array()[..], expected[32..]);
+//! let (left, right) = t64.split_at::<51>();
+//! assert_eq!(left.into_array()[..], expected[..51]);
+//! assert_eq!(right.into_array()[..], expected[51..]);
+//! # }
+//! ```
+//! 
+//! The `frunk` feature adds conversions to and from frunk's `HList`.
+//! 
+//! The `serde` feature adds `Flat`, which serializes tuples of any supported length.
+//! 
+//! The `derive` feature adds `#[derive(IntoTuple, FromTuple, Joinable)]` for structs, and
+//! `joinable!` to declare structs joining the fields of two others.
+//! 
+//! The `alloc` feature adds `TransposeResult::transpose_all`, which collects errors into a `Vec`.
+//! It also implements [`Leaf`] for `alloc` types such as `String`, `Vec` and `Box`.
 //! 
 //! # Examples
 //! 
@@ -14,8 +112,175 @@
 //! 
 //! assert_eq!((1,2,3).push("hello"), (1,2,3,"hello"));
 //! assert_eq!(("ferris", "the", "rustacean").pop(), (("ferris", "the"), "rustacean"));
+//! 
+//! assert_eq!((2,3).push_front(1), (1,2,3));
+//! assert_eq!(("ferris", "the", "rustacean").pop_front(), ("ferris", ("the", "rustacean")));
+//! 
+//! assert_eq!((1,2,3,4,5,6).split_at::<4>(), ((1,2,3,4), (5,6)));
+//! 
+//! assert_eq!((1,2,3).reverse(), (3,2,1));
+//! assert_eq!((1,2).join((3,4,5)).reverse(), (3,4,5).reverse().join((1,2).reverse()));
 //! ```
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod cons;
+/// Derive [`IntoTuple`] and [`FromTuple`], mapping fields in declaration order.
+/// 
+/// ```
+/// use tuple_join::*;
+/// 
+/// #[derive(IntoTuple, FromTuple, Debug, PartialEq)]
+/// struct User {
+///     id: u32,
+///     name: &'static str,
+/// }
+/// 
+/// #[derive(IntoTuple, FromTuple, Debug, PartialEq)]
+/// struct Flags(bool, bool);
+/// 
+/// let row = User { id: 1, name: "ferris" }.into_tuple().join(Flags(true, false).into_tuple());
+/// assert_eq!(row, (1, "ferris", true, false));
+/// 
+/// let (user, flags) = row.split();
+/// assert_eq!(User::from_tuple(user), User { id: 1, name: "ferris" });
+/// assert_eq!(Flags::from_tuple(flags), Flags(true, false));
+/// ```
+#[cfg(feature = "derive")]
+pub use tuple_join_derive::IntoTuple;
+#[cfg(feature = "derive")]
+pub use tuple_join_derive::FromTuple;
+/// Declare field group structs and structs joining two of them.
+/// 
+/// `struct Name = Left + Right;` declares `Name` with the fields of `Left` followed by
+/// those of `Right`, so that `Left` joins with `Right` into `Name` through [`Join`],
+/// and `Name` splits back through [`Joined`].
+/// 
+/// `Left` and `Right` are structs declared in the same invocation, including structs
+/// joined above, or paths to structs deriving [`Joinable`](derive@Joinable) elsewhere in the crate.
+/// Every struct declared here derives `Joinable` too.
+/// 
+/// ```
+/// use tuple_join::*;
+/// 
+/// joinable! {
+///     #[derive(Debug, PartialEq)]
+///     pub struct Paging {
+///         pub page: u32,
+///         pub per_page: u32,
+///     }
+/// 
+///     #[derive(Debug, PartialEq)]
+///     pub struct Filter {
+///         pub query: &'static str,
+///     }
+/// 
+///     #[derive(Debug, PartialEq)]
+///     pub struct Search = Paging + Filter;
+/// 
+///     #[derive(Debug, PartialEq)]
+///     pub struct Sorting {
+///         pub descending: bool,
+///     }
+/// 
+///     #[derive(Debug, PartialEq)]
+///     pub struct SortedSearch = Search + Sorting;
+/// }
+/// 
+/// let search = Paging { page: 1, per_page: 20 }.join(Filter { query: "ferris" });
+/// assert_eq!(search, Search { page: 1, per_page: 20, query: "ferris" });
+/// 
+/// let (paging, filter): (Paging, Filter) = search.split();
+/// assert_eq!(paging, Paging { page: 1, per_page: 20 });
+/// assert_eq!(filter, Filter { query: "ferris" });
+/// 
+/// let sorted = paging.join(filter).join(Sorting { descending: true });
+/// assert_eq!(sorted.query, "ferris");
+/// let (_, sorting): (Search, Sorting) = sorted.split();
+/// assert_eq!(sorting, Sorting { descending: true });
+/// ```
+#[cfg(feature = "derive")]
+pub use tuple_join_derive::joinable;
+/// Make a struct a field group that [`joinable!`] can join from any module of the crate.
+/// 
+/// Also implements [`Tuple`], with the number of fields as `LEN`. Generic structs are not
+/// supported. The fields are copied into the joined struct as written, so their types
+/// must be nameable and their fields visible where `joinable!` is invoked.
+/// 
+/// ```
+/// use tuple_join::*;
+/// 
+/// mod paging {
+///     #[derive(tuple_join::Joinable, Debug, PartialEq)]
+///     pub struct Paging {
+///         pub page: u32,
+///         pub per_page: u32,
+///     }
+/// }
+/// 
+/// mod filter {
+///     #[derive(tuple_join::Joinable, Debug, PartialEq)]
+///     pub struct Filter {
+///         pub query: &'static str,
+///     }
+/// }
+/// 
+/// mod search {
+///     use crate::{filter::Filter, paging::Paging};
+/// 
+///     tuple_join::joinable! {
+///         #[derive(Debug, PartialEq)]
+///         pub struct Search = Paging + Filter;
+/// 
+///         #[derive(Debug, PartialEq)]
+///         pub struct Sorting {
+///             pub descending: bool,
+///         }
+///     }
+/// }
+/// 
+/// joinable! {
+///     #[derive(Debug, PartialEq)]
+///     pub struct SortedSearch = search::Search + search::Sorting;
+/// }
+/// 
+/// # fn main() {
+/// let search = paging::Paging { page: 1, per_page: 20 }.join(filter::Filter { query: "ferris" });
+/// assert_eq!(search, search::Search { page: 1, per_page: 20, query: "ferris" });
+/// assert_eq!(<paging::Paging as Tuple>::LEN, 2);
+/// 
+/// let sorted = search.join(search::Sorting { descending: true });
+/// assert_eq!(<SortedSearch as Tuple>::LEN, 4);
+/// let (search, _): (search::Search, search::Sorting) = sorted.split();
+/// let (paging, filter): (paging::Paging, filter::Filter) = search.split();
+/// assert_eq!((paging.page, filter.query), (1, "ferris"));
+/// # }
+/// ```
+#[cfg(feature = "derive")]
+pub use tuple_join_derive::Joinable;
+#[doc(hidden)]
+#[cfg(feature = "derive")]
+pub use tuple_join_derive::__join_fields;
+mod big;
+pub use big::Big;
+mod future;
+pub use future::{JoinAll, JoinFutures, TryJoinAll};
+mod iter;
+pub use iter::{UnzipAll, ZipAll, ZipAllIter, ZipLongestIter};
+mod partial;
+pub use partial::{bind, curry, uncurry, Curried, Partial, Uncurried};
+#[cfg(feature = "frunk")]
+mod frunk;
+#[cfg(feature = "frunk")]
+pub use crate::frunk::{FromFrunk, IntoFrunk};
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "serde")]
+pub use crate::serde::Flat;
+use core::marker::PhantomData;
+use cons::{Concat, Cons, ConsRefs, FlattenCons, FoldCons, GetCons, Index, InsertCons, IntoCons, IntoMapCons, MapCons, RemoveCons, ReverseOnto, SplitCons, ToPeano, TransposeOptionCons, TransposeResultCons, UnzipCons, ZipCons};
+
 /// Append a regular type to a tuple type.
 pub trait Append<A>: Join<(A,)> {
     fn push(self, other: A) -> Self::Out where Self: Sized, A: Sized;
@@ -29,106 +294,900 @@ impl<A, T> Append<A> for T where T: Join<(A,)>{
 
     fn pop(tuple: Self::Out) -> (Self, A) where Self: Sized, A: Sized {
         let (a, (b, )) = Self::split(tuple);
-        return (a, b);
+        (a, b)
     }
 }
 
+/// Prepend a regular type to a tuple type.
+pub trait Prepend<A> {
+    type Out: Tuple;
+
+    fn push_front(self, other: A) -> Self::Out where Self: Sized, A: Sized;
+    fn pop_front(tuple: Self::Out) -> (A, Self) where Self: Sized, A: Sized;
+}
+
+impl<A, T> Prepend<A> for T where (A,): Join<T>{
+    type Out = <(A,) as Join<T>>::Out;
+
+    fn push_front(self, other: A) -> Self::Out where Self: Sized, A: Sized {
+        (other,).join(self)
+    }
+
+    fn pop_front(tuple: Self::Out) -> (A, Self) where Self: Sized, A: Sized {
+        let ((a, ), b) = <(A,) as Join<T>>::split(tuple);
+        (a, b)
+    }
+}
+
+/// A tuple type of length `LEN`.
+/// 
+/// Structs deriving `Joinable`, including every struct declared by `joinable!`, implement
+/// this too with their number of fields, as the output of [`Join`] must be a `Tuple`,
+/// so bounds on `Tuple` accept those structs besides tuples.
+pub trait Tuple {
+    const LEN: usize;
+}
+
 /// Join 2 tuple types as the associated type.
+/// 
+/// The length of `Out` is always `LEFT_LEN + RIGHT_LEN`.
+/// 
+/// ```
+/// use tuple_join::*;
+/// 
+/// fn joined_len<A: Join<B>, B>() -> usize {
+///     <A::Out as Tuple>::LEN
+/// }
+/// 
+/// const _: () = assert!(<(u8, u16) as Join<(u32,)>>::LEFT_LEN == 2);
+/// const _: () = assert!(<<(u8, u16) as Join<(u32,)>>::Out as Tuple>::LEN == 3);
+/// assert_eq!(joined_len::<(u8,), (u16, u32, u64)>(), 4);
+/// ```
 pub trait Join<A> {
-    type Out;
+    type Out: Tuple;
+    const LEFT_LEN: usize;
+    const RIGHT_LEN: usize;
 
     fn join(self, other: A) -> Self::Out where Self: Sized, A: Sized;
     fn split(tuple: Self::Out) -> (Self, A) where Self: Sized, A: Sized;
 }
 
+/// Access the element at a const index of a tuple type.
+/// 
+/// ```
+/// use tuple_join::*;
+/// 
+/// fn last<T: Get<2>>(tuple: &T) -> &T::Output {
+///     tuple.get()
+/// }
+/// 
+/// let mut tuple = (1, "two").join((3.0,));
+/// assert_eq!(last(&tuple), &3.0);
+/// *tuple.get_at_mut::<1>() = "deux";
+/// assert_eq!(tuple.get_at::<0>(), &1);
+/// assert_eq!(tuple.into_get_at::<1>(), "deux");
+/// ```
+pub trait Get<const N: usize> {
+    type Output;
+
+    fn get(&self) -> &Self::Output;
+    fn get_mut(&mut self) -> &mut Self::Output;
+    fn into_get(self) -> Self::Output where Self: Sized;
+}
+
+/// Insert a regular type at a const index of a tuple type.
+/// 
+/// ```
+/// use tuple_join::*;
+/// 
+/// assert_eq!(InsertAt::<1, _>::insert_at((1, 3), 2), (1, 2, 3));
+/// assert_eq!(RemoveAt::<1>::remove_at((1, 2, 3)), (2, (1, 3)));
+/// assert_eq!((1, 3).insert_at::<1>(2), (1, 2, 3));
+/// assert_eq!((1, 2, 3).remove_at::<1>(), (2, (1, 3)));
+/// ```
+pub trait InsertAt<const N: usize, X> {
+    type Out;
+
+    fn insert_at(tuple: Self, x: X) -> Self::Out where Self: Sized;
+}
+
+/// Remove the element at a const index of a tuple type.
+pub trait RemoveAt<const N: usize> {
+    type Output;
+    type Out;
+
+    fn remove_at(tuple: Self) -> (Self::Output, Self::Out) where Self: Sized;
+}
+
+/// Reverse the order of a tuple type.
+pub trait Reverse {
+    type Out;
+
+    fn reverse(self) -> Self::Out where Self: Sized;
+}
+
+/// Flatten depth that splices tuple elements one level.
+pub struct Shallow;
+
+/// Flatten depth that splices nested tuple elements recursively.
+pub struct Deep;
+
+/// A type kept as a single element by [`Flatten`].
+/// 
+/// Implemented for primitives, `fn` pointers and common `core` types, and for `alloc` types
+/// such as `String`, `Vec` and `Box` with the `alloc` feature.
+/// Implement this for your own types to use them in flattened tuples.
+/// 
+/// Types from other crates cannot implement `Leaf` outside of this crate,
+/// wrap them in a newtype instead:
+/// 
+/// ```
+/// use tuple_join::*;
+/// use std::collections::HashMap;
+/// 
+/// struct Scores(HashMap<&'static str, u32>);
+/// 
+/// impl Leaf for Scores {}
+/// 
+/// let nested = ((1, Scores(HashMap::new())), ('c',));
+/// let (one, scores, c) = nested.flatten::<Deep>();
+/// assert_eq!((one, scores.0.len(), c), (1, 0, 'c'));
+/// ```
+pub trait Leaf {}
+
+/// Flatten nested tuples into a single tuple, `D` being [`Shallow`] or [`Deep`].
+/// 
+/// Every element that is not a tuple has to implement [`Leaf`], at both depths.
+/// 
+/// ```
+/// use tuple_join::*;
+/// 
+/// let nested = ((1, 2), 3, (4, (5,)));
+/// assert_eq!(nested.flatten::<Shallow>(), (1, 2, 3, 4, (5,)));
+/// assert_eq!(nested.flatten::<Deep>(), (1, 2, 3, 4, 5));
+/// 
+/// let double: fn(i32) -> i32 = |x| x * 2;
+/// let (f, x) = ((double,), 21).flatten::<Shallow>();
+/// assert_eq!(f(x), 42);
+/// 
+/// let unflattened: ((i32, i32), i32) = (1, 2, 3).unflatten::<Deep, _>();
+/// assert_eq!(unflattened, ((1, 2), 3));
+/// ```
+pub trait Flatten<D> {
+    type Out;
+
+    fn flatten(tuple: Self) -> Self::Out where Self: Sized;
+    fn unflatten(tuple: Self::Out) -> Self where Self: Sized;
+}
+
+/// Flatten a tuple with a depth, or unflatten it into a target shape.
+pub trait Flattenable {
+    fn flatten<D>(self) -> <Self as Flatten<D>>::Out where Self: Flatten<D> + Sized {
+        Flatten::<D>::flatten(self)
+    }
+
+    fn unflatten<D, T>(self) -> T where T: Flatten<D, Out = Self>, Self: Sized {
+        T::unflatten(self)
+    }
+}
+
+impl<T> Flattenable for T where T: IntoCons {}
+
+/// A function generic over its argument type, implemented once per argument type.
+pub trait PolyFn<T> {
+    type Output;
+
+    fn call(&mut self, x: T) -> Self::Output;
+}
+
+/// Map every element of a tuple type with a [`PolyFn`].
+/// 
+/// Implemented for tuples and references to tuples.
+/// 
+/// ```
+/// use tuple_join::*;
+/// 
+/// struct Double;
+/// 
+/// impl PolyFn<i32> for Double {
+///     type Output = i32;
+///     fn call(&mut self, x: i32) -> i32 { x * 2 }
+/// }
+/// 
+/// impl PolyFn<&'static str> for Double {
+///     type Output = String;
+///     fn call(&mut self, x: &'static str) -> String { x.repeat(2) }
+/// }
+/// 
+/// impl<'a> PolyFn<&'a mut i32> for Double {
+///     type Output = ();
+///     fn call(&mut self, x: &'a mut i32) { *x *= 2 }
+/// }
+/// 
+/// assert_eq!((1, "a").join((3,)).map(Double), (1, "a").map(Double).join((3,).map(Double)));
+/// 
+/// let mut tuple = (1, 2);
+/// tuple.map_mut(Double);
+/// assert_eq!(tuple, (2, 4));
+/// ```
+pub trait TupleMap<F> {
+    type Out;
+
+    fn map(self, f: F) -> Self::Out where Self: Sized;
+}
+
+/// Map the elements of a tuple by reference.
+pub trait Mappable {
+    fn map_ref<'a, F>(&'a self, f: F) -> <&'a Self as TupleMap<F>>::Out where &'a Self: TupleMap<F> {
+        TupleMap::map(self, f)
+    }
+
+    fn map_mut<'a, F>(&'a mut self, f: F) -> <&'a mut Self as TupleMap<F>>::Out where &'a mut Self: TupleMap<F> {
+        TupleMap::map(self, f)
+    }
+}
+
+impl<T> Mappable for T where T: IntoCons {}
+
+/// Call a function with the elements of a tuple type as arguments.
+/// 
+/// Implemented for tuples and references to tuples.
+/// 
+/// ```
+/// use tuple_join::*;
+/// 
+/// fn handler(id: u32, name: &str, admin: bool) -> String {
+///     format!("{id} {name} {admin}")
+/// }
+/// 
+/// let args = (1, "ferris").join((true,));
+/// assert_eq!(args.apply(handler), "1 ferris true");
+/// 
+/// let mut count = 0;
+/// (1, 2).apply(|a, b| count += a + b);
+/// assert_eq!(count, 3);
+/// 
+/// let mut tuple = (1, String::from("a"));
+/// assert_eq!(tuple.apply_ref(|a, b| format!("{a}{b}")), "1a");
+/// tuple.apply_mut(|a, b| { *a += 1; b.push('b') });
+/// assert_eq!(tuple, (2, String::from("ab")));
+/// ```
+pub trait Apply<F> {
+    type Output;
+
+    fn apply(self, f: F) -> Self::Output where Self: Sized;
+}
+
+/// Call a function with references to the elements of a tuple as arguments.
+pub trait Applicable {
+    fn apply_ref<'a, F>(&'a self, f: F) -> <&'a Self as Apply<F>>::Output where &'a Self: Apply<F> {
+        Apply::apply(self, f)
+    }
+
+    fn apply_mut<'a, F>(&'a mut self, f: F) -> <&'a mut Self as Apply<F>>::Output where &'a mut Self: Apply<F> {
+        Apply::apply(self, f)
+    }
+}
+
+impl<T> Applicable for T where T: IntoCons {}
+
+/// A folding function generic over its element type, implemented once per element type.
+pub trait FoldFn<Acc, T> {
+    type Output;
+
+    fn call(&mut self, acc: Acc, x: T) -> Self::Output;
+}
+
+/// Fold every element of a tuple type from the left with a [`FoldFn`].
+/// 
+/// ```
+/// use tuple_join::*;
+/// 
+/// struct Size;
+/// 
+/// impl<T> FoldFn<usize, T> for Size {
+///     type Output = usize;
+///     fn call(&mut self, acc: usize, x: T) -> usize { acc + core::mem::size_of_val(&x) }
+/// }
+/// 
+/// struct Show;
+/// 
+/// impl<T: core::fmt::Display> FoldFn<String, T> for Show {
+///     type Output = String;
+///     fn call(&mut self, acc: String, x: T) -> String { format!("{acc}{x}") }
+/// }
+/// 
+/// let (a, b): ((u8, u16), (u32,)) = (1u8, 2u16, 3u32).split();
+/// assert_eq!(a.fold(0, Size) + b.fold(0, Size), 7);
+/// assert_eq!((1, 'b', "c").fold(String::new(), Show), "1bc");
+/// assert_eq!((1, 'b', "c").fold_right(String::new(), Show), "cb1");
+/// ```
+pub trait TupleFold<Acc, F> {
+    type Out;
+
+    fn fold(self, acc: Acc, f: F) -> Self::Out where Self: Sized;
+}
+
+/// Fold every element of a tuple type from the right with a [`FoldFn`].
+pub trait TupleFoldRight<Acc, F> {
+    type Out;
+
+    fn fold_right(self, acc: Acc, f: F) -> Self::Out where Self: Sized;
+}
+
+/// Zip two tuple types of the same length into a tuple of pairs.
+/// 
+/// ```
+/// use tuple_join::*;
+/// 
+/// let zipped = (1, 'b', "c").zip(("x", 2.0, 'z'));
+/// assert_eq!(zipped, ((1, "x"), ('b', 2.0), ("c", 'z')));
+/// assert_eq!(zipped.unzip(), ((1, 'b', "c"), ("x", 2.0, 'z')));
+/// ```
+pub trait Zip<U> {
+    type Out;
+
+    fn zip(self, other: U) -> Self::Out where Self: Sized, U: Sized;
+}
+
+/// Unzip a tuple of pairs into two tuples.
+pub trait Unzip {
+    type Left;
+    type Right;
+
+    fn unzip(self) -> (Self::Left, Self::Right) where Self: Sized;
+}
+
+/// Transpose a tuple of options into an option of a tuple.
+///
+/// Transposing commutes with [`Join`].
+///
+/// ```
+/// use tuple_join::*;
+///
+/// let a = (Some(1), Some('b'));
+/// let b = (Some("c"),);
+/// assert_eq!(a.transpose_option(), Some((1, 'b')));
+/// assert_eq!((Some(1), None::<char>).transpose_option(), None);
+/// assert_eq!(().transpose_option(), Some(()));
+/// assert_eq!(a.join(b).transpose_option(), a.transpose_option().zip(b.transpose_option()).map(|(a, b)| a.join(b)));
+///
+/// assert_eq!(<(Option<i32>, Option<char>)>::untranspose_option(Some((1, 'b'))), (Some(1), Some('b')));
+/// assert_eq!(<(Option<i32>, Option<char>)>::untranspose_option(None), (None, None));
+/// ```
+pub trait TransposeOption {
+    type Out;
+
+    fn transpose_option(self) -> Option<Self::Out> where Self: Sized;
+    fn untranspose_option(option: Option<Self::Out>) -> Self where Self: Sized;
+}
+
+/// Transpose a tuple of results into a result of a tuple.
+///
+/// ```
+/// use tuple_join::*;
+///
+/// let valid = (Ok::<_, &str>(1), Ok('b'));
+/// assert_eq!(valid.transpose_result(), Ok((1, 'b')));
+/// assert_eq!((Ok(1), Err("b"), Err("c")).transpose_result(), Err::<(i32, char, bool), _>("b"));
+///
+/// let mut errors = [None; 3];
+/// let out = (Ok::<i32, _>(1), Err("b"), Err::<bool, _>("c")).transpose_with(|i, e| errors[i] = Some(e));
+/// assert_eq!(out, None::<(i32, char, bool)>);
+/// assert_eq!(errors, [None, Some("b"), Some("c")]);
+/// ```
+pub trait TransposeResult<E> {
+    type Out;
+
+    /// Returns the first error.
+    fn transpose_result(self) -> Result<Self::Out, E> where Self: Sized;
+
+    /// Calls `f` with every error and its index, returns `None` if there were any.
+    fn transpose_with(self, f: impl FnMut(usize, E)) -> Option<Self::Out> where Self: Sized;
+
+    /// Returns every error with its index.
+    ///
+    /// ```
+    /// use tuple_join::*;
+    ///
+    /// let results = (Ok::<i32, &str>(1), Err::<char, _>("b"), Err::<bool, _>("c"));
+    /// assert_eq!(results.transpose_all(), Err(vec![(1, "b"), (2, "c")]));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn transpose_all(self) -> Result<Self::Out, alloc::vec::Vec<(usize, E)>> where Self: Sized {
+        let mut errors = alloc::vec::Vec::new();
+        self.transpose_with(|index, e| errors.push((index, e))).ok_or(errors)
+    }
+}
+
+/// Convert a tuple type with `N` elements of type `T` into an array.
+/// 
+/// ```
+/// use tuple_join::*;
+/// 
+/// let tuple = (1.0f32, 2.0).join((3.0,));
+/// assert_eq!(tuple.as_array_refs(), [&1.0, &2.0, &3.0]);
+/// assert_eq!(tuple.into_array(), [1.0, 2.0, 3.0]);
+/// assert_eq!(<(f32, f32)>::from_array([1.0, 2.0]), (1.0, 2.0));
+/// ```
+pub trait IntoArray<T, const N: usize> {
+    fn into_array(self) -> [T; N] where Self: Sized;
+    fn as_array_refs(&self) -> [&T; N];
+}
+
+/// Convert an array of `N` elements of type `T` into a tuple type.
+pub trait FromArray<T, const N: usize> {
+    fn from_array(array: [T; N]) -> Self where Self: Sized;
+}
+
+/// Convert a tuple type into its right-nested cons list `(A, (B, (C, ())))`.
+/// 
+/// Traits can be implemented once over the cons list instead of once per tuple length.
+/// 
+/// ```
+/// use tuple_join::*;
+/// 
+/// trait Sum {
+///     fn sum(self) -> i32;
+/// }
+/// 
+/// impl Sum for () {
+///     fn sum(self) -> i32 { 0 }
+/// }
+/// 
+/// impl<T: Sum> Sum for (i32, T) {
+///     fn sum(self) -> i32 { self.0 + self.1.sum() }
+/// }
+/// 
+/// assert_eq!((1, 2, 3).into_hlist(), (1, (2, (3, ()))));
+/// assert_eq!((1, 2).join((3,)).into_hlist().sum(), 6);
+/// assert_eq!(<(i32, i32)>::from_hlist((1, (2, ()))), (1, 2));
+/// ```
+pub trait IntoHList {
+    type HList;
+
+    fn into_hlist(self) -> Self::HList where Self: Sized;
+}
+
+/// Convert a right-nested cons list `(A, (B, (C, ())))` into a tuple type.
+pub trait FromHList: IntoHList {
+    fn from_hlist(hlist: Self::HList) -> Self where Self: Sized;
+}
+
+/// Join 2 cons lists as the associated type, the cons list counterpart of [`Join`].
+/// 
+/// Cons lists are tuples themselves, so [`Join`] treats them as pairs.
+/// 
+/// ```
+/// use tuple_join::*;
+/// 
+/// let joined = (1, (2, ())).join_hlist(("3", ()));
+/// assert_eq!(joined, (1, (2, ("3", ()))));
+/// assert_eq!(<(i32, (i32, ()))>::split_hlist(joined), ((1, (2, ())), ("3", ())));
+/// ```
+pub trait HListJoin<A> {
+    type Out;
+
+    fn join_hlist(self, other: A) -> Self::Out where Self: Sized, A: Sized;
+    fn split_hlist(hlist: Self::Out) -> (Self, A) where Self: Sized, A: Sized;
+}
+
+/// Convert a struct into a flat tuple of its fields in declaration order.
+/// 
+/// Derivable with the `derive` feature.
+pub trait IntoTuple {
+    type Tuple: Tuple;
+
+    fn into_tuple(self) -> Self::Tuple where Self: Sized;
+}
+
+/// Convert a flat tuple of fields in declaration order into a struct.
+/// 
+/// Derivable with the `derive` feature.
+pub trait FromTuple: IntoTuple {
+    fn from_tuple(tuple: Self::Tuple) -> Self where Self: Sized;
+}
+
 /// Split a regular from a tuple type.
 pub trait Appended<A, B> {
     fn pop(self) -> (A, B);
 }
 
+/// Split a regular type from the front of a tuple type.
+pub trait Prepended<A, B> {
+    fn pop_front(self) -> (A, B);
+}
+
 /// Split two tuple types from a tuple type.
 pub trait Joined<A, B> {
     fn split(self) -> (A, B);
 }
 
+/// Split a tuple type at a const index.
+pub trait SplitAt<const N: usize> {
+    type Left;
+    type Right;
+
+    fn split_at(tuple: Self) -> (Self::Left, Self::Right) where Self: Sized;
+}
+
+/// Split a tuple at a const index, without naming the resulting types.
+pub trait Splittable {
+    fn split_at<const N: usize>(self) -> (<Self as SplitAt<N>>::Left, <Self as SplitAt<N>>::Right) where Self: SplitAt<N> + Sized {
+        SplitAt::<N>::split_at(self)
+    }
+}
+
+impl<T> Splittable for T where T: IntoCons {}
+
+/// Access the element at a const index of a tuple, without naming the element type.
+pub trait Gettable {
+    fn get_at<const N: usize>(&self) -> &<Self as Get<N>>::Output where Self: Get<N> {
+        Get::<N>::get(self)
+    }
+
+    fn get_at_mut<const N: usize>(&mut self) -> &mut <Self as Get<N>>::Output where Self: Get<N> {
+        Get::<N>::get_mut(self)
+    }
+
+    fn into_get_at<const N: usize>(self) -> <Self as Get<N>>::Output where Self: Get<N> + Sized {
+        Get::<N>::into_get(self)
+    }
+}
+
+impl<T> Gettable for T where T: IntoCons {}
+
+/// Insert into a tuple at a const index, without naming the resulting type.
+pub trait Insertable<X> {
+    fn insert_at<const N: usize>(self, x: X) -> <Self as InsertAt<N, X>>::Out where Self: InsertAt<N, X> + Sized {
+        InsertAt::<N, X>::insert_at(self, x)
+    }
+}
+
+impl<T, X> Insertable<X> for T where T: IntoCons {}
+
+/// Remove from a tuple at a const index, without naming the resulting types.
+pub trait Removable {
+    fn remove_at<const N: usize>(self) -> (<Self as RemoveAt<N>>::Output, <Self as RemoveAt<N>>::Out) where Self: RemoveAt<N> + Sized {
+        RemoveAt::<N>::remove_at(self)
+    }
+}
+
+impl<T> Removable for T where T: IntoCons {}
+
 impl<A, B, T> Appended<A, B> for T where A: Append<B, Out = T>{
     fn pop(self) -> (A, B) {
         A::pop(self)
     }
 }
 
+impl<A, B, T> Prepended<A, B> for T where T: IntoCons, B: Prepend<A, Out = T>{
+    fn pop_front(self) -> (A, B) {
+        B::pop_front(self)
+    }
+}
+
 impl<A, B, T> Joined<A, B> for T where A: Join<B, Out = T>{
     fn split(self) -> (A, B) {
         A::split(self)
     }
 }
 
+impl<'a, T, F> Apply<F> for &'a T
+where
+    T: IntoCons,
+    <T::Cons as ConsRefs>::Ref<'a>: Cons,
+    <<T::Cons as ConsRefs>::Ref<'a> as Cons>::Tuple: Apply<F>,
+{
+    type Output = <<<T::Cons as ConsRefs>::Ref<'a> as Cons>::Tuple as Apply<F>>::Output;
+
+    fn apply(self, f: F) -> Self::Output {
+        self.cons_ref().into_tuple().apply(f)
+    }
+}
 
-macro_rules! tuple_join_y {
-    ($($x: ident)* ,) => {
-        impl<$($x,)*> Join<()> for ($($x,)*) {
-            type Out = ($($x,)*);
+impl<'a, T, F> Apply<F> for &'a mut T
+where
+    T: IntoCons,
+    <T::Cons as ConsRefs>::Mut<'a>: Cons,
+    <<T::Cons as ConsRefs>::Mut<'a> as Cons>::Tuple: Apply<F>,
+{
+    type Output = <<<T::Cons as ConsRefs>::Mut<'a> as Cons>::Tuple as Apply<F>>::Output;
 
-            fn join(self, _: ()) -> Self::Out {
-                self
-            }
-            fn split(out: Self::Out) -> (Self, ()) {
-                (out, ())
-            }
-        }
-    };
-    ($($x: ident)*, $y0:ident $($y: ident)*) => {
-        impl<$($x,)* $y0,$($y,)*> Join<($y0,$($y,)*)> for ($($x,)*) {
-            type Out = ($($x,)* $y0,$($y,)*);
-
-            fn join(self, ($y0,$($y,)*): ($y0,$($y,)*)) -> Self::Out {
-                let ($($x,)*) = self;
-                ($($x,)* $y0,$($y,)*)
-            }
-
-            fn split(($($x,)* $y0,$($y,)*): Self::Out) -> (Self, ($y0,$($y,)*)) {
-                (($($x,)*), ($y0,$($y,)*))
-            }
-        }
-        tuple_join_y!($($x)*, $($y)*);
-    };
+    fn apply(self, f: F) -> Self::Output {
+        self.cons_mut().into_tuple().apply(f)
+    }
 }
 
-macro_rules! tuple_join {
-    (, $y0:ident $($y: ident)*) => {
-        impl<$y0,$($y,)*> Join<($y0,$($y),*)> for () {
-            type Out = ($y0,$($y,)*);
-            
-            fn join(self, other: ($y0,$($y,)*)) -> Self::Out {
-                other
-            }
-
-            fn split(out: Self::Out) -> (Self, ($y0,$($y,)*)) {
-                ((), out)
-            }
-        }
-        tuple_join_y!(, $($y)*);
-    };
+impl<T, const N: usize> Get<N> for T
+where
+    T: IntoCons,
+    Index<N>: ToPeano,
+    T::Cons: GetCons<<Index<N> as ToPeano>::Peano>,
+{
+    type Output = <T::Cons as GetCons<<Index<N> as ToPeano>::Peano>>::Output;
 
-    ($x0: ident $($x: ident)*, $y0:ident $($y: ident)*) => {
-        impl<$x0,$($x,)* $y0,$($y,)*> Join<($y0,$($y,)*)> for ($x0,$($x,)*) {
-            type Out = ($x0,$($x,)* $y0,$($y,)*);
-
-            fn join(self, ($y0,$($y,)*): ($y0,$($y,)*)) -> Self::Out {
-                let ($x0,$($x,)*) = self;
-                ($x0,$($x,)* $y0,$($y,)*)
-            }
-            fn split(($x0,$($x,)* $y0,$($y,)*): Self::Out) -> (Self, ($y0,$($y,)*)) {
-                (($x0,$($x,)*), ($y0,$($y,)*))
-            }
-        }
-        tuple_join!($($x)*, $y0 $($y)*);
-        tuple_join_y!($x0 $($x)*, $($y)*);
-    };
+    fn get(&self) -> &Self::Output {
+        T::Cons::get_ref(self.cons_ref())
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Output {
+        T::Cons::get_mut(self.cons_mut())
+    }
+
+    fn into_get(self) -> Self::Output {
+        self.into_cons().get_cons()
+    }
+}
+
+impl<T, X, const N: usize> InsertAt<N, X> for T
+where
+    T: IntoCons,
+    Index<N>: ToPeano,
+    T::Cons: InsertCons<<Index<N> as ToPeano>::Peano, X>,
+    <T::Cons as InsertCons<<Index<N> as ToPeano>::Peano, X>>::Out: Cons,
+{
+    type Out = <<T::Cons as InsertCons<<Index<N> as ToPeano>::Peano, X>>::Out as Cons>::Tuple;
+
+    fn insert_at(tuple: Self, x: X) -> Self::Out {
+        tuple.into_cons().insert_cons(x).into_tuple()
+    }
+}
+
+impl<T, const N: usize> RemoveAt<N> for T
+where
+    T: IntoCons,
+    Index<N>: ToPeano,
+    T::Cons: RemoveCons<<Index<N> as ToPeano>::Peano>,
+    <T::Cons as RemoveCons<<Index<N> as ToPeano>::Peano>>::Out: Cons,
+{
+    type Output = <T::Cons as RemoveCons<<Index<N> as ToPeano>::Peano>>::Output;
+    type Out = <<T::Cons as RemoveCons<<Index<N> as ToPeano>::Peano>>::Out as Cons>::Tuple;
+
+    fn remove_at(tuple: Self) -> (Self::Output, Self::Out) {
+        let (output, rest) = tuple.into_cons().remove_cons();
+        (output, rest.into_tuple())
+    }
 }
 
+impl<T, D> Flatten<D> for T
+where
+    T: IntoCons,
+    T::Cons: FlattenCons<D>,
+    <T::Cons as FlattenCons<D>>::Out: Cons,
+{
+    type Out = <<T::Cons as FlattenCons<D>>::Out as Cons>::Tuple;
 
-tuple_join!(
-    A B C D E F G H I J K L M,
-    N O P Q R S T U V W X Y Z
+    fn flatten(tuple: Self) -> Self::Out {
+        tuple.into_cons().flatten_cons().into_tuple()
+    }
+
+    fn unflatten(tuple: Self::Out) -> Self {
+        T::from_cons(FlattenCons::unflatten_cons(Cons::from_tuple(tuple)))
+    }
+}
+
+macro_rules! impl_leaf {
+    ($($ty: ty),* $(,)?) => {
+        $(impl Leaf for $ty {})*
+    };
+}
+
+impl_leaf!(
+    bool, char,
+    i8, i16, i32, i64, i128, isize,
+    u8, u16, u32, u64, u128, usize,
+    f32, f64,
+    core::num::NonZeroI8, core::num::NonZeroI16, core::num::NonZeroI32,
+    core::num::NonZeroI64, core::num::NonZeroI128, core::num::NonZeroIsize,
+    core::num::NonZeroU8, core::num::NonZeroU16, core::num::NonZeroU32,
+    core::num::NonZeroU64, core::num::NonZeroU128, core::num::NonZeroUsize,
+    core::time::Duration, core::cmp::Ordering, core::any::TypeId, core::ops::RangeFull,
+    core::net::IpAddr, core::net::Ipv4Addr, core::net::Ipv6Addr,
+    core::net::SocketAddr, core::net::SocketAddrV4, core::net::SocketAddrV6,
 );
+
+impl<T: ?Sized> Leaf for &T {}
+impl<T: ?Sized> Leaf for &mut T {}
+impl<T: ?Sized> Leaf for *const T {}
+impl<T: ?Sized> Leaf for *mut T {}
+impl<T: ?Sized> Leaf for core::ptr::NonNull<T> {}
+impl<T, const N: usize> Leaf for [T; N] {}
+impl<T> Leaf for Option<T> {}
+impl<T, E> Leaf for Result<T, E> {}
+impl<T: ?Sized> Leaf for PhantomData<T> {}
+impl<T> Leaf for core::num::Wrapping<T> {}
+impl<T> Leaf for core::num::Saturating<T> {}
+impl<T> Leaf for core::cmp::Reverse<T> {}
+impl<T> Leaf for core::cell::Cell<T> {}
+impl<T> Leaf for core::cell::RefCell<T> {}
+impl<T> Leaf for core::cell::OnceCell<T> {}
+impl<T> Leaf for core::mem::ManuallyDrop<T> {}
+impl<T> Leaf for core::pin::Pin<T> {}
+impl<T> Leaf for core::task::Poll<T> {}
+impl<T> Leaf for core::ops::Range<T> {}
+impl<T> Leaf for core::ops::RangeInclusive<T> {}
+impl<T> Leaf for core::ops::RangeFrom<T> {}
+impl<T> Leaf for core::ops::RangeTo<T> {}
+impl<T> Leaf for core::ops::RangeToInclusive<T> {}
+
+#[cfg(feature = "alloc")]
+impl Leaf for alloc::string::String {}
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> Leaf for alloc::boxed::Box<T> {}
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> Leaf for alloc::rc::Rc<T> {}
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T: ?Sized> Leaf for alloc::sync::Arc<T> {}
+#[cfg(feature = "alloc")]
+impl<B: ?Sized + alloc::borrow::ToOwned> Leaf for alloc::borrow::Cow<'_, B> {}
+#[cfg(feature = "alloc")]
+impl<T> Leaf for alloc::vec::Vec<T> {}
+#[cfg(feature = "alloc")]
+impl<T> Leaf for alloc::collections::VecDeque<T> {}
+#[cfg(feature = "alloc")]
+impl<T> Leaf for alloc::collections::LinkedList<T> {}
+#[cfg(feature = "alloc")]
+impl<T> Leaf for alloc::collections::BinaryHeap<T> {}
+#[cfg(feature = "alloc")]
+impl<T> Leaf for alloc::collections::BTreeSet<T> {}
+#[cfg(feature = "alloc")]
+impl<K, V> Leaf for alloc::collections::BTreeMap<K, V> {}
+
+impl<T, F> TupleMap<F> for T
+where
+    T: IntoMapCons,
+    T::Cons: MapCons<F>,
+    <T::Cons as MapCons<F>>::Out: Cons,
+{
+    type Out = <<T::Cons as MapCons<F>>::Out as Cons>::Tuple;
+
+    fn map(self, mut f: F) -> Self::Out {
+        self.into_map_cons().map_cons(&mut f).into_tuple()
+    }
+}
+
+impl<T, Acc, F> TupleFold<Acc, F> for T
+where
+    T: IntoCons,
+    T::Cons: FoldCons<Acc, F>,
+{
+    type Out = <T::Cons as FoldCons<Acc, F>>::Out;
+
+    fn fold(self, acc: Acc, mut f: F) -> Self::Out {
+        self.into_cons().fold_cons(acc, &mut f)
+    }
+}
+
+impl<T, Acc, F> TupleFoldRight<Acc, F> for T
+where
+    T: IntoCons,
+    T::Cons: ReverseOnto<()>,
+    <T::Cons as ReverseOnto<()>>::Out: FoldCons<Acc, F>,
+{
+    type Out = <<T::Cons as ReverseOnto<()>>::Out as FoldCons<Acc, F>>::Out;
+
+    fn fold_right(self, acc: Acc, mut f: F) -> Self::Out {
+        self.into_cons().reverse_onto(()).fold_cons(acc, &mut f)
+    }
+}
+
+impl<T, U> Zip<U> for T
+where
+    T: IntoCons,
+    U: IntoCons,
+    T::Cons: ZipCons<U::Cons>,
+    <T::Cons as ZipCons<U::Cons>>::Out: Cons,
+{
+    type Out = <<T::Cons as ZipCons<U::Cons>>::Out as Cons>::Tuple;
+
+    fn zip(self, other: U) -> Self::Out {
+        self.into_cons().zip_cons(other.into_cons()).into_tuple()
+    }
+}
+
+impl<T> Unzip for T
+where
+    T: IntoCons,
+    T::Cons: UnzipCons,
+    <T::Cons as UnzipCons>::Left: Cons,
+    <T::Cons as UnzipCons>::Right: Cons,
+{
+    type Left = <<T::Cons as UnzipCons>::Left as Cons>::Tuple;
+    type Right = <<T::Cons as UnzipCons>::Right as Cons>::Tuple;
+
+    fn unzip(self) -> (Self::Left, Self::Right) {
+        let (left, right) = self.into_cons().unzip_cons();
+        (left.into_tuple(), right.into_tuple())
+    }
+}
+
+impl<T> TransposeOption for T
+where
+    T: IntoCons,
+    T::Cons: TransposeOptionCons,
+    <T::Cons as TransposeOptionCons>::Out: Cons,
+{
+    type Out = <<T::Cons as TransposeOptionCons>::Out as Cons>::Tuple;
+
+    fn transpose_option(self) -> Option<Self::Out> {
+        self.into_cons().transpose_cons().map(Cons::into_tuple)
+    }
+
+    fn untranspose_option(option: Option<Self::Out>) -> Self {
+        T::from_cons(T::Cons::untranspose_cons(option.map(Cons::from_tuple)))
+    }
+}
+
+impl<T, E> TransposeResult<E> for T
+where
+    T: IntoCons,
+    T::Cons: TransposeResultCons<E>,
+    <T::Cons as TransposeResultCons<E>>::Out: Cons,
+{
+    type Out = <<T::Cons as TransposeResultCons<E>>::Out as Cons>::Tuple;
+
+    fn transpose_result(self) -> Result<Self::Out, E> {
+        self.into_cons().transpose_cons().map(Cons::into_tuple)
+    }
+
+    fn transpose_with(self, mut f: impl FnMut(usize, E)) -> Option<Self::Out> {
+        self.into_cons().transpose_with_cons(0, &mut f).map(Cons::into_tuple)
+    }
+}
+
+impl<T> IntoHList for T where T: IntoCons {
+    type HList = T::Cons;
+
+    fn into_hlist(self) -> Self::HList {
+        self.into_cons()
+    }
+}
+
+impl<T> FromHList for T where T: IntoCons {
+    fn from_hlist(hlist: Self::HList) -> Self {
+        T::from_cons(hlist)
+    }
+}
+
+impl<T, A> HListJoin<A> for T where T: Concat<A> {
+    type Out = T::Out;
+
+    fn join_hlist(self, other: A) -> Self::Out {
+        self.concat(other)
+    }
+
+    fn split_hlist(hlist: Self::Out) -> (Self, A) {
+        T::split(hlist)
+    }
+}
+
+impl<T> Reverse for T
+where
+    T: IntoCons,
+    T::Cons: ReverseOnto<()>,
+    <T::Cons as ReverseOnto<()>>::Out: Cons,
+{
+    type Out = <<T::Cons as ReverseOnto<()>>::Out as Cons>::Tuple;
+
+    fn reverse(self) -> Self::Out {
+        self.into_cons().reverse_onto(()).into_tuple()
+    }
+}
+
+impl<T, const N: usize> SplitAt<N> for T
+where
+    T: IntoCons,
+    Index<N>: ToPeano,
+    T::Cons: SplitCons<<Index<N> as ToPeano>::Peano>,
+    <T::Cons as SplitCons<<Index<N> as ToPeano>::Peano>>::Left: Cons,
+    <T::Cons as SplitCons<<Index<N> as ToPeano>::Peano>>::Right: Cons,
+    <<T::Cons as SplitCons<<Index<N> as ToPeano>::Peano>>::Left as Cons>::Tuple: Join<<<T::Cons as SplitCons<<Index<N> as ToPeano>::Peano>>::Right as Cons>::Tuple, Out = T>,
+{
+    type Left = <<T::Cons as SplitCons<<Index<N> as ToPeano>::Peano>>::Left as Cons>::Tuple;
+    type Right = <<T::Cons as SplitCons<<Index<N> as ToPeano>::Peano>>::Right as Cons>::Tuple;
+
+    fn split_at(tuple: Self) -> (Self::Left, Self::Right) {
+        Join::split(tuple)
+    }
+}
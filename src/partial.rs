@@ -0,0 +1,144 @@
+//! Partial application and currying of functions taking tuple elements as arguments.
+
+use core::marker::PhantomData;
+use crate::cons::IntoCons;
+use crate::{Append, Apply, Join};
+
+/// A function with its leading arguments bound, returned by [`bind`].
+///
+/// Calling it joins the bound arguments with the remaining ones.
+#[derive(Debug, Clone, Copy)]
+pub struct Partial<F, A> {
+    f: F,
+    args: A,
+}
+
+/// Bind the leading arguments of `f`.
+///
+/// ```
+/// use tuple_join::*;
+///
+/// fn handle(db: &str, user: u32, path: &str, verbose: bool) -> String {
+///     format!("{db}:{user}:{path}:{verbose}")
+/// }
+///
+/// let handler = bind(handle, ("main", 7));
+/// assert_eq!(handler.call(("/index", true)), "main:7:/index:true");
+/// assert_eq!(handler.call(("/about", false)), "main:7:/about:false");
+///
+/// let mut log = Vec::new();
+/// let mut push = bind(|a: &str, b: &str| log.push(format!("{a}{b}")), ("> ",));
+/// push.call_mut(("hello",));
+/// push.call_once(("world",));
+/// assert_eq!(log, ["> hello", "> world"]);
+/// ```
+pub fn bind<F, A>(f: F, args: A) -> Partial<F, A> {
+    Partial { f, args }
+}
+
+impl<F, A> Partial<F, A> {
+    pub fn call<'a, B>(&'a self, rest: B) -> <A::Out as Apply<&'a F>>::Output where A: Join<B> + Clone, A::Out: Apply<&'a F> {
+        self.args.clone().join(rest).apply(&self.f)
+    }
+
+    pub fn call_mut<'a, B>(&'a mut self, rest: B) -> <A::Out as Apply<&'a mut F>>::Output where A: Join<B> + Clone, A::Out: Apply<&'a mut F> {
+        self.args.clone().join(rest).apply(&mut self.f)
+    }
+
+    pub fn call_once<B>(self, rest: B) -> <A::Out as Apply<F>>::Output where A: Join<B>, A::Out: Apply<F> {
+        self.args.join(rest).apply(self.f)
+    }
+}
+
+/// A function taking one argument at a time, returned by [`curry`].
+///
+/// `Args` are the arguments taken so far and `Rest` the cons list of remaining argument types.
+#[derive(Debug, Clone, Copy)]
+pub struct Curried<F, Args, Rest> {
+    f: F,
+    args: Args,
+    rest: PhantomData<fn(Rest)>,
+}
+
+/// Curry a function taking the elements of `T` as arguments.
+///
+/// ```
+/// use tuple_join::*;
+///
+/// let add = curry::<(i32, i32, i32), _>(|a, b, c| a * b + c);
+/// assert_eq!(add.call(2).call(3).call(4), 10);
+///
+/// let zero = curry::<(), _>(|| 0);
+/// assert_eq!(zero.call(), 0);
+/// ```
+pub fn curry<T, F>(f: F) -> Curried<F, (), T::Cons> where T: IntoCons {
+    Curried { f, args: (), rest: PhantomData }
+}
+
+impl<F, Args> Curried<F, Args, ()> {
+    pub fn call(self) -> <Args as Apply<F>>::Output where Args: Apply<F> {
+        self.args.apply(self.f)
+    }
+}
+
+impl<F, Args, H> Curried<F, Args, (H, ())> {
+    pub fn call(self, x: H) -> <Args::Out as Apply<F>>::Output where Args: Append<H>, Args::Out: Apply<F> {
+        self.args.push(x).apply(self.f)
+    }
+}
+
+impl<F, Args, H, H2, T> Curried<F, Args, (H, (H2, T))> {
+    pub fn call(self, x: H) -> Curried<F, Args::Out, (H2, T)> where Args: Append<H> {
+        Curried { f: self.f, args: self.args.push(x), rest: PhantomData }
+    }
+}
+
+/// Call nested single argument functions with the elements of a cons list in order.
+pub trait UncurryCons<G> {
+    type Output;
+
+    fn uncurry_cons(self, g: G) -> Self::Output;
+}
+
+impl<G> UncurryCons<G> for () {
+    type Output = G;
+
+    fn uncurry_cons(self, g: G) -> Self::Output {
+        g
+    }
+}
+
+impl<G, G2, H, T> UncurryCons<G> for (H, T) where G: FnOnce(H) -> G2, T: UncurryCons<G2> {
+    type Output = T::Output;
+
+    fn uncurry_cons(self, g: G) -> Self::Output {
+        self.1.uncurry_cons(g(self.0))
+    }
+}
+
+/// Nested single argument functions taking a tuple of arguments, returned by [`uncurry`].
+#[derive(Debug, Clone, Copy)]
+pub struct Uncurried<G>(G);
+
+/// Uncurry nested single argument functions into a function taking a tuple.
+///
+/// ```
+/// use tuple_join::*;
+///
+/// let add = uncurry(|a: i32| move |b: i32| move |c: i32| a * b + c);
+/// assert_eq!(add.call((2, 3, 4)), 10);
+/// assert_eq!(add.call((2, 3).join((5,))), 11);
+/// ```
+pub fn uncurry<G>(g: G) -> Uncurried<G> {
+    Uncurried(g)
+}
+
+impl<G> Uncurried<G> {
+    pub fn call<'a, T>(&'a self, args: T) -> <T::Cons as UncurryCons<&'a G>>::Output where T: IntoCons, T::Cons: UncurryCons<&'a G> {
+        args.into_cons().uncurry_cons(&self.0)
+    }
+
+    pub fn call_once<T>(self, args: T) -> <T::Cons as UncurryCons<G>>::Output where T: IntoCons, T::Cons: UncurryCons<G> {
+        args.into_cons().uncurry_cons(self.0)
+    }
+}
@@ -0,0 +1,156 @@
+//! Serialization of tuples of any supported length, enabled by the `serde` feature.
+
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::value::SeqDeserializer;
+use serde::de::{Deserialize, Deserializer, Error, Expected, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+use crate::cons::{ConsRefs, IntoCons};
+use crate::Join;
+
+/// Serialize the elements of a cons list from its references.
+pub trait SerializeCons: ConsRefs {
+    fn serialize_cons<S: SerializeTuple>(refs: Self::Ref<'_>, seq: &mut S) -> Result<(), S::Error>;
+}
+
+impl SerializeCons for () {
+    fn serialize_cons<S: SerializeTuple>(_: Self::Ref<'_>, _: &mut S) -> Result<(), S::Error> {
+        Ok(())
+    }
+}
+
+impl<H, T> SerializeCons for (H, T) where H: Serialize, T: SerializeCons {
+    fn serialize_cons<S: SerializeTuple>(refs: Self::Ref<'_>, seq: &mut S) -> Result<(), S::Error> {
+        seq.serialize_element(refs.0)?;
+        T::serialize_cons(refs.1, seq)
+    }
+}
+
+/// Deserialize the elements of a cons list from a sequence.
+pub trait DeserializeCons<'de>: Sized {
+    fn deserialize_cons<A: SeqAccess<'de>>(seq: &mut A, index: usize, exp: &dyn Expected) -> Result<Self, A::Error>;
+}
+
+impl<'de> DeserializeCons<'de> for () {
+    fn deserialize_cons<A: SeqAccess<'de>>(_: &mut A, _: usize, _: &dyn Expected) -> Result<Self, A::Error> {
+        Ok(())
+    }
+}
+
+impl<'de, H, T> DeserializeCons<'de> for (H, T) where H: Deserialize<'de>, T: DeserializeCons<'de> {
+    fn deserialize_cons<A: SeqAccess<'de>>(seq: &mut A, index: usize, exp: &dyn Expected) -> Result<Self, A::Error> {
+        let head = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(index, exp))?;
+        Ok((head, T::deserialize_cons(seq, index + 1, exp)?))
+    }
+}
+
+/// Serializes and deserializes a tuple of any supported length as a sequence.
+///
+/// The format is the same as serde's own impls for tuples of length up to 16,
+/// the empty tuple is a unit like `()`.
+///
+/// ```
+/// use tuple_join::*;
+///
+/// let tuple = (1, 2, 3, 4, 5, 6, 7, 8, 9).join((10, 11, 12, 13, 14, 15, 16, 17, 18));
+/// let json = serde_json::to_string(&Flat(tuple)).unwrap();
+/// assert_eq!(json, "[1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18]");
+///
+/// let mut back = tuple;
+/// back = serde_json::from_str::<Flat<_>>(&json).unwrap().0;
+/// assert_eq!(back.into_array(), tuple.into_array());
+///
+/// assert_eq!(serde_json::to_string(&Flat(())).unwrap(), "null");
+/// assert_eq!(serde_json::from_str::<Flat<()>>("null").unwrap(), Flat(()));
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Flat<T>(pub T);
+
+impl<T> Serialize for Flat<T> where T: IntoCons, T::Cons: SerializeCons {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if T::LEN == 0 {
+            return serializer.serialize_unit();
+        }
+        let mut seq = serializer.serialize_tuple(T::LEN)?;
+        T::Cons::serialize_cons(self.0.cons_ref(), &mut seq)?;
+        seq.end()
+    }
+}
+
+struct FlatVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for FlatVisitor<T> where T: IntoCons, T::Cons: DeserializeCons<'de> {
+    type Value = Flat<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a tuple of length {}", T::LEN)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let cons = T::Cons::deserialize_cons(&mut seq, 0, &self)?;
+        Ok(Flat(T::from_cons(cons)))
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        self.visit_seq(SeqDeserializer::new(core::iter::empty::<()>()))
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Flat<T> where T: IntoCons, T::Cons: DeserializeCons<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if T::LEN == 0 {
+            return deserializer.deserialize_unit(FlatVisitor(PhantomData));
+        }
+        deserializer.deserialize_tuple(T::LEN, FlatVisitor(PhantomData))
+    }
+}
+
+/// Serializes a pair of tuples `(A, B)` as the flat sequence of `A` joined with `B`.
+///
+/// Use with `#[serde(with = "tuple_join::serde::joined")]`.
+///
+/// ```
+/// use serde::{Serialize, Deserialize};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Row {
+///     #[serde(with = "tuple_join::serde::joined")]
+///     parts: ((i32, bool), (char,)),
+/// }
+///
+/// let row = Row { parts: ((1, true), ('c',)) };
+/// let json = serde_json::to_string(&row).unwrap();
+/// assert_eq!(json, r#"{"parts":[1,true,"c"]}"#);
+/// assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+/// ```
+pub mod joined {
+    use super::*;
+
+    pub fn serialize<A, B, S>(tuple: &(A, B), serializer: S) -> Result<S::Ok, S::Error>
+    where
+        A: IntoCons,
+        B: IntoCons,
+        A::Cons: SerializeCons,
+        B::Cons: SerializeCons,
+        S: Serializer,
+    {
+        if A::LEN + B::LEN == 0 {
+            return serializer.serialize_unit();
+        }
+        let mut seq = serializer.serialize_tuple(A::LEN + B::LEN)?;
+        A::Cons::serialize_cons(tuple.0.cons_ref(), &mut seq)?;
+        B::Cons::serialize_cons(tuple.1.cons_ref(), &mut seq)?;
+        seq.end()
+    }
+
+    pub fn deserialize<'de, A, B, D>(deserializer: D) -> Result<(A, B), D::Error>
+    where
+        A: Join<B>,
+        Flat<A::Out>: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let Flat(tuple) = Flat::<A::Out>::deserialize(deserializer)?;
+        Ok(A::split(tuple))
+    }
+}
@@ -0,0 +1,341 @@
+//! Derive macros for `tuple_join`, enabled by its `derive` feature.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Error, Fields, Ident, Index, ItemStruct, Member, Path, Token, Type, Visibility};
+
+/// The fields of a struct as bindings in declaration order.
+struct StructFields {
+    /// `{ a, b }`, `(field_0, field_1)` or nothing, to destructure or construct the struct.
+    pattern: TokenStream2,
+    bindings: Vec<Ident>,
+    types: Vec<Type>,
+}
+
+impl StructFields {
+    fn new(input: &DeriveInput) -> syn::Result<Self> {
+        let Data::Struct(data) = &input.data else {
+            return Err(Error::new_spanned(&input.ident, "only structs can be converted to and from tuples"));
+        };
+        let types = data.fields.iter().map(|field| field.ty.clone()).collect();
+        let (pattern, bindings) = match &data.fields {
+            Fields::Named(fields) => {
+                let bindings: Vec<_> = fields.named.iter().map(|field| field.ident.clone().unwrap()).collect();
+                (quote!({ #(#bindings),* }), bindings)
+            },
+            Fields::Unnamed(fields) => {
+                let bindings: Vec<_> = (0..fields.unnamed.len()).map(|i| format_ident!("field_{}", i)).collect();
+                (quote!(( #(#bindings),* )), bindings)
+            },
+            Fields::Unit => (quote!(), Vec::new()),
+        };
+        Ok(StructFields { pattern, bindings, types })
+    }
+}
+
+/// Derive `IntoTuple`, converting a struct into a flat tuple of its fields in declaration order.
+#[proc_macro_derive(IntoTuple)]
+pub fn derive_into_tuple(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    into_tuple(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Derive `FromTuple`, converting a flat tuple of fields in declaration order into a struct.
+#[proc_macro_derive(FromTuple)]
+pub fn derive_from_tuple(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_tuple(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn into_tuple(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let StructFields { pattern, bindings, types } = StructFields::new(input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::tuple_join::IntoTuple for #name #ty_generics #where_clause {
+            type Tuple = (#(#types,)*);
+
+            fn into_tuple(self) -> Self::Tuple {
+                let Self #pattern = self;
+                (#(#bindings,)*)
+            }
+        }
+    })
+}
+
+fn from_tuple(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let StructFields { pattern, bindings, .. } = StructFields::new(input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::tuple_join::FromTuple for #name #ty_generics #where_clause {
+            fn from_tuple((#(#bindings,)*): Self::Tuple) -> Self {
+                Self #pattern
+            }
+        }
+    })
+}
+
+/// Derive `Joinable`, making a struct a field group that `joinable!` can join from any module.
+///
+/// Emits a hidden macro named after the struct that passes its fields on, and implements
+/// `Tuple` with the number of fields as `LEN`.
+#[proc_macro_derive(Joinable)]
+pub fn derive_joinable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    joinable_fields(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn joinable_fields(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(&input.ident, "only structs can be joined"));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(&input.generics, "generic structs cannot be joined"));
+    }
+    let name = &input.ident;
+    let fields = &data.fields;
+    let body = match fields {
+        Fields::Named(_) => quote!(#fields),
+        Fields::Unnamed(_) | Fields::Unit => quote!(#fields;),
+    };
+    let len = fields.len();
+    let mac = format_ident!("__tuple_join_fields_{}", name);
+    Ok(quote! {
+        #[doc(hidden)]
+        macro_rules! #mac {
+            ($($input: tt)*) => {
+                ::tuple_join::__join_fields! { $($input)* struct #name #body }
+            };
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use #mac as #name;
+
+        impl ::tuple_join::Tuple for #name {
+            const LEN: usize = #len;
+        }
+    })
+}
+
+/// `#attrs #vis struct Name = Left + Right;`
+struct Combined {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    left: Path,
+    right: Path,
+}
+
+impl Parse for Combined {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        input.parse::<Token![struct]>()?;
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let left = input.call(Path::parse_mod_style)?;
+        input.parse::<Token![+]>()?;
+        let right = input.call(Path::parse_mod_style)?;
+        input.parse::<Token![;]>()?;
+        Ok(Combined { attrs, vis, name, left, right })
+    }
+}
+
+impl ToTokens for Combined {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let Combined { attrs, vis, name, left, right } = self;
+        tokens.extend(quote!(#(#attrs)* #vis struct #name = #left + #right;));
+    }
+}
+
+/// Field group structs and the structs combining them.
+struct Joinable {
+    groups: Vec<ItemStruct>,
+    combined: Vec<Combined>,
+}
+
+impl Parse for Joinable {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut joinable = Joinable { groups: Vec::new(), combined: Vec::new() };
+        while !input.is_empty() {
+            let fork = input.fork();
+            fork.call(Attribute::parse_outer)?;
+            fork.parse::<Visibility>()?;
+            fork.parse::<Token![struct]>()?;
+            fork.parse::<Ident>()?;
+            if fork.peek(Token![=]) {
+                joinable.combined.push(input.parse()?);
+            } else {
+                joinable.groups.push(input.parse()?);
+            }
+        }
+        Ok(joinable)
+    }
+}
+
+/// Declare field group structs and structs combining two of them.
+///
+/// For every `struct Name = Left + Right;` this generates `Name` with the fields of
+/// `Left` followed by those of `Right`, and implements `Join<Right>` for `Left`
+/// with `Name` as its output.
+///
+/// `Left` and `Right` are either declared in the same invocation, or paths to structs
+/// deriving `Joinable`. Every struct declared here derives `Joinable` itself.
+#[proc_macro]
+pub fn joinable(input: TokenStream) -> TokenStream {
+    let Joinable { mut groups, combined } = parse_macro_input!(input as Joinable);
+    for group in &mut groups {
+        group.attrs.push(parse_quote!(#[derive(::tuple_join::Joinable)]));
+    }
+    let mut impls = Vec::new();
+    for (i, current) in combined.iter().enumerate() {
+        let duplicate = combined[..i].iter()
+            .find(|prev| path_name(&prev.left) == path_name(&current.left) && path_name(&prev.right) == path_name(&current.right));
+        if let Some(prev) = duplicate {
+            return Error::new_spanned(&current.name, format!(
+                "`{}` and `{}` are already joined into `{}`", path_name(&current.left), path_name(&current.right), prev.name
+            )).into_compile_error().into();
+        }
+        let (Some(left), Some(right)) = (find_group(&groups, &current.left), find_group(&groups, &current.right)) else {
+            impls.push(quote!(::tuple_join::__join_fields! { #current }));
+            continue;
+        };
+        match join_structs(current, left, right) {
+            Ok((definition, join)) => {
+                groups.push(definition);
+                impls.push(join);
+            },
+            Err(err) => return err.into_compile_error().into(),
+        }
+    }
+    quote!(#(#groups)* #(#impls)*).into()
+}
+
+/// A combined struct declaration, followed by the fields of the structs it joins as far
+/// as they have been collected, from the macros `#[derive(Joinable)]` emits.
+struct JoinFields {
+    combined: Combined,
+    structs: Vec<ItemStruct>,
+}
+
+impl Parse for JoinFields {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let combined = input.parse()?;
+        let mut structs = Vec::new();
+        while !input.is_empty() {
+            structs.push(input.parse()?);
+        }
+        Ok(JoinFields { combined, structs })
+    }
+}
+
+/// Collects the fields of `Left` and then `Right` by calling their macros, then joins them.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __join_fields(input: TokenStream) -> TokenStream {
+    let JoinFields { combined, structs } = parse_macro_input!(input as JoinFields);
+    let (left, right) = match &structs[..] {
+        [] => {
+            let left = &combined.left;
+            return quote!(#left! { #combined }).into();
+        },
+        [left] => {
+            let right = &combined.right;
+            return quote!(#right! { #combined #left }).into();
+        },
+        [left, right] => (left, right),
+        [_, _, extra, ..] => return Error::new_spanned(extra, "unexpected struct").into_compile_error().into(),
+    };
+    match join_structs(&combined, left, right) {
+        Ok((definition, join)) => quote!(#definition #join).into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// `a::b::C` for error messages.
+fn path_name(path: &Path) -> String {
+    path.segments.iter().map(|segment| segment.ident.to_string()).collect::<Vec<_>>().join("::")
+}
+
+/// The struct declared in this invocation that `path` names, if any.
+fn find_group<'a>(groups: &'a [ItemStruct], path: &Path) -> Option<&'a ItemStruct> {
+    let name = path.get_ident()?;
+    groups.iter().find(|group| &group.ident == name)
+}
+
+/// Members of fields, numbering unnamed ones from `offset`.
+fn members(fields: &Fields, offset: usize) -> Vec<Member> {
+    fields.iter().enumerate().map(|(i, field)| match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(offset + i)),
+    }).collect()
+}
+
+/// Bindings that cannot collide with names from the macro input.
+fn bindings(offset: usize, len: usize) -> Vec<Ident> {
+    (offset..offset + len).map(|i| format_ident!("field_{}", i, span = Span::mixed_site())).collect()
+}
+
+/// The combined struct, deriving `Joinable` to be joined itself, and the `Join` impl.
+fn join_structs(combined: &Combined, left_group: &ItemStruct, right_group: &ItemStruct) -> syn::Result<(ItemStruct, TokenStream2)> {
+    let Combined { attrs, vis, name, left, right } = combined;
+    let attrs = quote!(#(#attrs)* #[derive(::tuple_join::Joinable)]);
+    for group in [left_group, right_group] {
+        if !group.generics.params.is_empty() {
+            return Err(Error::new_spanned(&group.generics, "generic structs cannot be joined"));
+        }
+    }
+    let left_fields = &left_group.fields;
+    let right_fields = &right_group.fields;
+    let fields = left_fields.iter().chain(right_fields);
+    let definition = match (left_fields, right_fields) {
+        (Fields::Unit, Fields::Unit) => quote!(#attrs #vis struct #name;),
+        (Fields::Named(_) | Fields::Unit, Fields::Named(_) | Fields::Unit) => {
+            for field in right_fields {
+                if let Some(prev) = left_fields.iter().find(|prev| prev.ident == field.ident) {
+                    let ident = prev.ident.as_ref().unwrap();
+                    return Err(Error::new_spanned(&field.ident, format!("field `{}` is also declared in `{}`", ident, path_name(left))));
+                }
+            }
+            quote!(#attrs #vis struct #name { #(#fields),* })
+        },
+        (Fields::Unnamed(_) | Fields::Unit, Fields::Unnamed(_) | Fields::Unit) => {
+            quote!(#attrs #vis struct #name ( #(#fields),* );)
+        },
+        _ => return Err(Error::new_spanned(name, "cannot join a struct with named fields and a tuple struct")),
+    };
+    let left_len = left_fields.len();
+    let right_len = right_fields.len();
+    let left_members = members(left_fields, 0);
+    let right_members = members(right_fields, 0);
+    let members = members(left_fields, 0).into_iter().chain(members(right_fields, left_len));
+    let left_bindings = bindings(0, left_len);
+    let right_bindings = bindings(left_len, right_len);
+    let all_bindings = left_bindings.iter().chain(&right_bindings);
+    let left_pattern = quote!(#left { #(#left_members: #left_bindings),* });
+    let right_pattern = quote!(#right { #(#right_members: #right_bindings),* });
+    let pattern = quote!(#name { #(#members: #all_bindings),* });
+    let join = quote! {
+        impl ::tuple_join::Join<#right> for #left {
+            type Out = #name;
+            const LEFT_LEN: usize = #left_len;
+            const RIGHT_LEN: usize = #right_len;
+
+            fn join(self, other: #right) -> Self::Out {
+                let #left_pattern = self;
+                let #right_pattern = other;
+                #pattern
+            }
+
+            fn split(tuple: Self::Out) -> (Self, #right) {
+                let #pattern = tuple;
+                (#left_pattern, #right_pattern)
+            }
+        }
+    };
+    Ok((syn::parse2(definition)?, join))
+}